@@ -0,0 +1,100 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::AlgebraicSponge;
+use snarkvm_fields::{PrimeField, ToConstraintField};
+
+use core::{fmt, marker::PhantomData};
+use merlin::Transcript;
+use smallvec::SmallVec;
+
+/// The number of challenge bytes used to sample a full field element.
+/// Sampling twice the field size makes the bias from the modular reduction negligible.
+const FULL_CHALLENGE_SIZE_IN_BYTES: usize = 64;
+/// The number of challenge bytes used to sample a short (168-bit) field element.
+const SHORT_CHALLENGE_SIZE_IN_BYTES: usize = 21;
+
+/// A Fiat-Shamir sponge backed by a Merlin (STROBE-128) transcript.
+///
+/// This allows a Varuna instance to use the same transcript as external verifiers that use Merlin.
+/// The challenges are reproducible, given the same label and the same sequence of absorbed messages.
+#[derive(Clone)]
+pub struct MerlinSponge<F: PrimeField> {
+    /// The Merlin transcript.
+    transcript: Transcript,
+    _field: PhantomData<F>,
+}
+
+impl<F: PrimeField> fmt::Debug for MerlinSponge<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MerlinSponge").finish_non_exhaustive()
+    }
+}
+
+impl<F: PrimeField, const RATE: usize> AlgebraicSponge<F, RATE> for MerlinSponge<F> {
+    /// The label of the transcript.
+    type Parameters = &'static [u8];
+
+    fn sample_parameters() -> Self::Parameters {
+        b"snarkVM-Varuna"
+    }
+
+    fn new_with_parameters(label: &Self::Parameters) -> Self {
+        Self { transcript: Transcript::new(label), _field: PhantomData }
+    }
+
+    /// Takes in field elements.
+    fn absorb_native_field_elements<T: ToConstraintField<F>>(&mut self, elements: &[T]) {
+        for element in elements.iter().flat_map(|e| e.to_field_elements().unwrap()) {
+            self.transcript.append_message(b"native", &element.to_bytes_le().unwrap());
+        }
+    }
+
+    /// Takes in field elements.
+    fn absorb_nonnative_field_elements<Target: PrimeField>(&mut self, elements: impl IntoIterator<Item = Target>) {
+        for element in elements {
+            self.transcript.append_message(b"nonnative", &element.to_bytes_le().unwrap());
+        }
+    }
+
+    fn squeeze_native_field_elements(&mut self, num: usize) -> SmallVec<[F; 10]> {
+        self.squeeze::<F, FULL_CHALLENGE_SIZE_IN_BYTES>(b"native", num)
+    }
+
+    fn squeeze_nonnative_field_elements<Target: PrimeField>(&mut self, num: usize) -> SmallVec<[Target; 10]> {
+        self.squeeze::<Target, FULL_CHALLENGE_SIZE_IN_BYTES>(b"nonnative", num)
+    }
+
+    /// Takes out field elements of 168 bits.
+    fn squeeze_short_nonnative_field_elements<Target: PrimeField>(&mut self, num: usize) -> SmallVec<[Target; 10]> {
+        self.squeeze::<Target, SHORT_CHALLENGE_SIZE_IN_BYTES>(b"short_nonnative", num)
+    }
+}
+
+impl<F: PrimeField> MerlinSponge<F> {
+    /// Returns `num` field elements, each sampled from `NUM_BYTES` challenge bytes of the transcript.
+    fn squeeze<Target: PrimeField, const NUM_BYTES: usize>(
+        &mut self,
+        label: &'static [u8],
+        num: usize,
+    ) -> SmallVec<[Target; 10]> {
+        (0..num)
+            .map(|_| {
+                let mut bytes = [0u8; NUM_BYTES];
+                self.transcript.challenge_bytes(label, &mut bytes);
+                Target::from_bytes_le_mod_order(&bytes)
+            })
+            .collect()
+    }
+}
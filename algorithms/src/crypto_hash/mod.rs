@@ -12,6 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(feature = "merlin")]
+pub mod merlin_sponge;
+#[cfg(feature = "merlin")]
+pub use merlin_sponge::*;
+
 pub mod poseidon;
 pub use poseidon::*;
 
@@ -86,3 +86,70 @@ fn bls12_377_fr_poseidon_default_parameters_test() {
     single_rate_test::<7>();
     single_rate_test::<8>();
 }
+
+#[test]
+fn test_poseidon_sponge_challenges_from_fixed_transcript() {
+    const RATE: usize = 2;
+    let sponge_param = Arc::new(Fr::default_poseidon_parameters::<RATE>().unwrap());
+    // Initializes a sponge from a fixed transcript.
+    let sponge_from_transcript = || {
+        let mut sponge = PoseidonSponge::<Fr, RATE, 1>::new_with_parameters(&sponge_param);
+        sponge.absorb_bytes(b"snarkVM Fiat-Shamir test vector");
+        sponge
+    };
+
+    // Ensure the challenge sequence is deterministic.
+    let expected = sponge_from_transcript().squeeze_native_field_elements(5);
+    assert_eq!(expected, sponge_from_transcript().squeeze_native_field_elements(5));
+
+    // Ensure the challenge sequence does not depend on how the squeezes are split.
+    for split in 0..=5 {
+        let mut sponge = sponge_from_transcript();
+        let mut candidate = sponge.squeeze_native_field_elements(split).to_vec();
+        candidate.extend(sponge.squeeze_native_field_elements(5 - split));
+        assert_eq!(expected.to_vec(), candidate, "Split at {split}");
+    }
+}
+
+#[cfg(feature = "merlin")]
+#[test]
+fn test_sponges_are_deterministic() {
+    use crate::crypto_hash::MerlinSponge;
+    use snarkvm_curves::bls12_377::Fq;
+
+    // Absorbs the given messages, and returns the squeezed native, nonnative, and short nonnative challenges.
+    #[allow(clippy::type_complexity)]
+    fn transcript<S: AlgebraicSponge<Fr, 2>>(
+        parameters: &S::Parameters,
+        native: &[Fr],
+        nonnative: &[Fq],
+        bytes: &[u8],
+    ) -> (Vec<Fr>, Vec<Fq>, Vec<Fq>) {
+        let mut sponge = S::new_with_parameters(parameters);
+        sponge.absorb_native_field_elements(native);
+        sponge.absorb_nonnative_field_elements(nonnative.iter().copied());
+        sponge.absorb_bytes(bytes);
+        (
+            sponge.squeeze_native_field_elements(3).to_vec(),
+            sponge.squeeze_nonnative_field_elements(3).to_vec(),
+            sponge.squeeze_short_nonnative_field_elements(3).to_vec(),
+        )
+    }
+
+    let native = [Fr::from(1237812u64), Fr::from(42u64)];
+    let nonnative = [Fq::from(7u64), Fq::from(1237812u64)];
+    let bytes = b"snarkVM".as_slice();
+
+    // Ensure the Poseidon sponge is deterministic, and depends on the absorbed messages.
+    let poseidon_parameters = <PoseidonSponge<Fr, 2, 1> as AlgebraicSponge<Fr, 2>>::sample_parameters();
+    let expected = transcript::<PoseidonSponge<Fr, 2, 1>>(&poseidon_parameters, &native, &nonnative, bytes);
+    assert_eq!(expected, transcript::<PoseidonSponge<Fr, 2, 1>>(&poseidon_parameters, &native, &nonnative, bytes));
+    assert_ne!(expected, transcript::<PoseidonSponge<Fr, 2, 1>>(&poseidon_parameters, &native, &nonnative, b"other"));
+
+    // Ensure the Merlin sponge is deterministic, and depends on the absorbed messages and the label.
+    let merlin_parameters = <MerlinSponge<Fr> as AlgebraicSponge<Fr, 2>>::sample_parameters();
+    let expected = transcript::<MerlinSponge<Fr>>(&merlin_parameters, &native, &nonnative, bytes);
+    assert_eq!(expected, transcript::<MerlinSponge<Fr>>(&merlin_parameters, &native, &nonnative, bytes));
+    assert_ne!(expected, transcript::<MerlinSponge<Fr>>(&merlin_parameters, &native, &nonnative, b"other"));
+    assert_ne!(expected, transcript::<MerlinSponge<Fr>>(&b"other".as_slice(), &native, &nonnative, bytes));
+}
@@ -53,3 +53,74 @@ impl<A: Aleo> ToFields for Record<A, Ciphertext<A>> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use console::ToFields as _;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    type CurrentNetwork = <Circuit as Environment>::Network;
+
+    fn sample_console_record(
+        nonce: console::Group<CurrentNetwork>,
+    ) -> console::Record<CurrentNetwork, console::Plaintext<CurrentNetwork>> {
+        console::Record::from_str(&format!(
+            r"{{
+    owner: aleo14tlamssdmg3d0p5zmljma573jghe2q9n6wz29qf36re2glcedcpqfg4add.private,
+    a: true.private,
+    b: 123456789field.public,
+    c: 0group.private,
+    d: {{
+        e: true.private,
+        f: 123456789field.private,
+        g: 0group.private
+    }},
+    _nonce: {nonce}.public
+}}"
+        ))
+        .unwrap()
+    }
+
+    fn check_to_fields(mode: Mode) -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample the record.
+        let randomizer = Uniform::rand(&mut rng);
+        let expected = sample_console_record(<CurrentNetwork as console::Network>::g_scalar_multiply(&randomizer));
+        let candidate = Record::<Circuit, Plaintext<Circuit>>::new(mode, expected.clone());
+        // Ensure the circuit record allocates the same field elements as the console record.
+        let expected_fields = expected.to_fields()?;
+        let candidate_fields = candidate.to_fields();
+        assert_eq!(expected_fields.len(), candidate_fields.len());
+        assert_eq!(expected_fields, candidate_fields.eject_value());
+
+        // Encrypt the record.
+        let expected = expected.encrypt(randomizer)?;
+        let candidate = Record::<Circuit, Ciphertext<Circuit>>::new(mode, expected.clone());
+        // Ensure the circuit record allocates the same field elements as the console record.
+        let expected_fields = expected.to_fields()?;
+        let candidate_fields = candidate.to_fields();
+        assert_eq!(expected_fields.len(), candidate_fields.len());
+        assert_eq!(expected_fields, candidate_fields.eject_value());
+
+        Circuit::reset();
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_fields_constant() -> Result<()> {
+        check_to_fields(Mode::Constant)
+    }
+
+    #[test]
+    fn test_to_fields_public() -> Result<()> {
+        check_to_fields(Mode::Public)
+    }
+
+    #[test]
+    fn test_to_fields_private() -> Result<()> {
+        check_to_fields(Mode::Private)
+    }
+}
@@ -0,0 +1,64 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+static ACCOUNT_CHILD_SEED_DOMAIN: &str = "AleoAccountChildSeed0";
+
+impl<N: Network> PrivateKey<N> {
+    /// Returns the child private key at the given index, derived deterministically from the account seed.
+    pub fn derive_child(&self, index: u32) -> Result<Self> {
+        // Construct the child seed domain separator.
+        let domain = Field::<N>::new_domain_separator(ACCOUNT_CHILD_SEED_DOMAIN);
+        // Compute the child seed as `Hash(domain, seed, index)`.
+        let child_seed = N::hash_psd4(&[domain, self.seed, Field::from_u32(index)])?;
+        // Derive the child private key from the child seed.
+        Self::try_from(child_seed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Address;
+    use snarkvm_console_network::Testnet3;
+
+    use std::collections::HashSet;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u32 = 1000;
+
+    #[test]
+    fn test_derive_child() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample a new private key.
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+
+        let mut addresses = HashSet::new();
+        for index in 0..ITERATIONS {
+            // Derive the child private key.
+            let child = private_key.derive_child(index)?;
+            // Ensure the derivation is deterministic.
+            assert_eq!(child, private_key.derive_child(index)?);
+            // Ensure the child differs from the parent.
+            assert_ne!(child, private_key);
+            // Ensure the child address is unique.
+            assert!(addresses.insert(Address::try_from(&child)?));
+        }
+        assert_eq!(ITERATIONS as usize, addresses.len());
+        Ok(())
+    }
+}
@@ -0,0 +1,112 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use bip39::{Language, Mnemonic};
+
+impl<N: Network> PrivateKey<N> {
+    /// Samples a new random private key, returning it with its BIP39 mnemonic phrase of the given word count.
+    /// The private key is derived from the mnemonic with an empty passphrase.
+    pub fn new_mnemonic<R: Rng + CryptoRng>(rng: &mut R, word_count: usize) -> Result<(Self, String)> {
+        // Ensure the word count is supported by BIP39.
+        ensure!(matches!(word_count, 12 | 15 | 18 | 21 | 24), "Invalid mnemonic word count: {word_count}");
+        // Sample the entropy, where every 3 words encode 4 bytes of entropy.
+        let mut entropy = vec![0u8; word_count / 3 * 4];
+        rng.fill_bytes(&mut entropy);
+        // Construct the mnemonic from the entropy.
+        let mnemonic = Mnemonic::from_entropy_in(Language::English, &entropy);
+        entropy.zeroize();
+        let phrase = match mnemonic {
+            Ok(mnemonic) => mnemonic.to_string(),
+            Err(error) => bail!("Failed to construct a mnemonic: {error}"),
+        };
+        // Derive the account private key from the mnemonic.
+        Ok((Self::from_mnemonic(&phrase, "")?, phrase))
+    }
+
+    /// Returns the account private key from a BIP39 mnemonic phrase and passphrase.
+    pub fn from_mnemonic(phrase: &str, passphrase: &str) -> Result<Self> {
+        // Parse the mnemonic, which ensures the words and checksum are valid.
+        let mnemonic = match Mnemonic::parse_in(Language::English, phrase) {
+            Ok(mnemonic) => mnemonic,
+            Err(error) => bail!("Invalid mnemonic: {error}"),
+        };
+        // Derive the BIP39 seed from the mnemonic and passphrase.
+        let seed = mnemonic.to_seed(passphrase);
+        // Derive the account private key from the BIP39 seed.
+        Self::try_from(Field::new(N::Field::from_bytes_le_mod_order(&seed)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Address;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const MNEMONIC: &str =
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    const ALEO_PRIVATE_KEY: &str = "APrivateKey1zkpBNNJDBu8ur7qVzEacfYwGAc8P7i7v3n5j7rDK2QSAnvK";
+    const ALEO_ADDRESS: &str = "aleo1hmfm5cmhl3healwcdx7yrdkqezcz2zzqk825m6gpk5awtvpkjuqq0g5naq";
+
+    #[test]
+    fn test_from_mnemonic() -> Result<()> {
+        // Derive the private key from the mnemonic.
+        let private_key = PrivateKey::<CurrentNetwork>::from_mnemonic(MNEMONIC, "")?;
+        let address = Address::try_from(&private_key)?;
+        assert_eq!(ALEO_PRIVATE_KEY, private_key.to_string());
+        assert_eq!(ALEO_ADDRESS, address.to_string());
+
+        // Ensure the derivation is deterministic.
+        let candidate = PrivateKey::<CurrentNetwork>::from_mnemonic(MNEMONIC, "")?;
+        assert_eq!(private_key, candidate);
+
+        // Ensure the passphrase changes the derived private key.
+        let candidate = PrivateKey::<CurrentNetwork>::from_mnemonic(MNEMONIC, "passphrase")?;
+        assert_ne!(private_key, candidate);
+        Ok(())
+    }
+
+    #[test]
+    fn test_new_mnemonic() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for word_count in [12, 15, 18, 21, 24] {
+            // Sample a new private key and its mnemonic.
+            let (private_key, phrase) = PrivateKey::<CurrentNetwork>::new_mnemonic(&mut rng, word_count)?;
+            assert_eq!(word_count, phrase.split_whitespace().count());
+            // Ensure the mnemonic recovers the same private key.
+            assert_eq!(private_key, PrivateKey::<CurrentNetwork>::from_mnemonic(&phrase, "")?);
+        }
+
+        // Ensure unsupported word counts are rejected.
+        for word_count in [0, 11, 13, 27] {
+            assert!(PrivateKey::<CurrentNetwork>::new_mnemonic(&mut rng, word_count).is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_mnemonic_invalid_checksum() {
+        // The last word of a valid 12-word mnemonic encodes its checksum.
+        let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon";
+        assert!(PrivateKey::<CurrentNetwork>::from_mnemonic(phrase, "").is_err());
+        // Ensure an unknown word is rejected.
+        let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon aleo";
+        assert!(PrivateKey::<CurrentNetwork>::from_mnemonic(phrase, "").is_err());
+    }
+}
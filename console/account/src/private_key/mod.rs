@@ -13,6 +13,8 @@
 // limitations under the License.
 
 mod bytes;
+mod derive;
+mod mnemonic;
 mod serialize;
 mod string;
 mod try_from;
@@ -25,6 +27,9 @@ use snarkvm_console_types::{Field, Scalar};
 
 use zeroize::Zeroize;
 
+/// The private key of an account.
+/// Note: `PrivateKey` is `Copy`, so it cannot zeroize itself on drop. To scrub a long-lived private key
+/// from memory once it is dropped, hold it in `zeroize::Zeroizing`.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Zeroize)]
 pub struct PrivateKey<N: Network> {
     /// The account seed that derives the full private key.
@@ -58,3 +63,34 @@ impl<N: Network> PrivateKey<N> {
         self.r_sig
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    use zeroize::Zeroizing;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_zeroize() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Ensure zeroizing a private key clears the seed and the derived secrets.
+        let mut private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        assert!(!private_key.seed().is_zero());
+        private_key.zeroize();
+        assert!(private_key.seed().is_zero());
+        assert!(private_key.sk_sig().is_zero());
+        assert!(private_key.r_sig().is_zero());
+
+        // Ensure a wrapped private key is usable, and is cleared when zeroized.
+        let expected = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let mut private_key = Zeroizing::new(expected);
+        assert_eq!(*private_key, expected);
+        private_key.zeroize();
+        assert!(private_key.seed().is_zero());
+        Ok(())
+    }
+}
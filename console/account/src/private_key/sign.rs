@@ -30,6 +30,11 @@ impl<N: Network> PrivateKey<N> {
     pub fn sign_bits<R: Rng + CryptoRng>(&self, message: &[bool], rng: &mut R) -> Result<Signature<N>> {
         Signature::sign_bits(self, message, rng)
     }
+
+    /// Returns a signature answering the given login challenge nonce, using the private key.
+    pub fn sign_challenge<R: Rng + CryptoRng>(&self, nonce: &[u8], rng: &mut R) -> Result<Signature<N>> {
+        Signature::sign_challenge(self, nonce, rng)
+    }
 }
 
 #[cfg(test)]
@@ -55,6 +55,9 @@ mod tests {
             // Check the byte representation.
             let signature_bytes = signature.to_bytes_le()?;
             assert_eq!(signature, Signature::read_le(&signature_bytes[..])?);
+            // Ensure the byte representation is the fixed signature size.
+            assert_eq!(signature_bytes.len(), Signature::<CurrentNetwork>::size_in_bytes());
+            assert_eq!(signature_bytes.len(), 128);
             assert!(Signature::<CurrentNetwork>::read_le(&signature_bytes[1..]).is_err());
         }
         Ok(())
@@ -0,0 +1,97 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The domain separator for login challenges.
+const CHALLENGE_DOMAIN: &str = "AleoLoginChallenge0";
+
+impl<N: Network> Signature<N> {
+    /// Returns a signature answering the given login challenge nonce, using the private key.
+    /// The signed message is (domain, nonce length, nonce), so a challenge response cannot be
+    /// replayed as a signature over other data, such as a request.
+    #[cfg(feature = "private_key")]
+    pub fn sign_challenge<R: Rng + CryptoRng>(
+        private_key: &PrivateKey<N>,
+        nonce: &[u8],
+        rng: &mut R,
+    ) -> Result<Signature<N>> {
+        Self::sign(private_key, &Self::challenge_message(nonce)?, rng)
+    }
+
+    /// Verifies a login challenge response for the given address and nonce.
+    pub fn verify_challenge(&self, address: &Address<N>, nonce: &[u8]) -> bool {
+        match Self::challenge_message(nonce) {
+            Ok(message) => self.verify(address, &message),
+            Err(error) => {
+                eprintln!("Failed to verify challenge: {error}");
+                false
+            }
+        }
+    }
+
+    /// Returns the message for the given login challenge nonce, as (domain, nonce length, nonce).
+    fn challenge_message(nonce: &[u8]) -> Result<Vec<Field<N>>> {
+        // Prepare the domain and the nonce length.
+        let mut message =
+            vec![Field::new_domain_separator(CHALLENGE_DOMAIN), Field::from_u64(u64::try_from(nonce.len())?)];
+        // Pack the nonce bits into field elements.
+        for bits in nonce.to_bits_le().chunks(Field::<N>::size_in_data_bits()) {
+            message.push(Field::from_bits_le(bits)?);
+        }
+        Ok(message)
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "private_key")]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_sign_and_verify_challenge() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample an address and a private key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let address = Address::try_from(&private_key)?;
+
+            // Check that the response is valid for the nonce.
+            let nonce: Vec<u8> = (0..i).map(|_| Uniform::rand(rng)).collect();
+            let signature = Signature::sign_challenge(&private_key, &nonce, rng)?;
+            assert!(signature.verify_challenge(&address, &nonce));
+
+            // Check that the response is invalid for an incorrect nonce.
+            let failure_nonce: Vec<u8> = (0..i).map(|_| Uniform::rand(rng)).collect();
+            if nonce != failure_nonce {
+                assert!(!signature.verify_challenge(&address, &failure_nonce));
+            }
+
+            // Check that the response is invalid when replayed for another address.
+            let other_address = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng)?)?;
+            assert!(!signature.verify_challenge(&other_address, &nonce));
+
+            // Check that the response is not a signature over the nonce itself.
+            assert!(!signature.verify_bytes(&address, &nonce));
+            assert!(!Signature::sign_bytes(&private_key, &nonce, rng)?.verify_challenge(&address, &nonce));
+        }
+        Ok(())
+    }
+}
@@ -14,6 +14,7 @@
 
 mod bitwise;
 mod bytes;
+mod challenge;
 mod from_bits;
 mod parse;
 mod serialize;
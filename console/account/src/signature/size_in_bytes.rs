@@ -16,6 +16,7 @@ use super::*;
 
 impl<N: Network> SizeInBytes for Signature<N> {
     /// Returns the signature size in bytes.
+    /// This is 128 bytes: the challenge, the response, and the x-coordinates of `pk_sig` and `pr_sig`.
     #[inline]
     fn size_in_bytes() -> usize {
         Scalar::<N>::size_in_bytes() + Scalar::<N>::size_in_bytes() + ComputeKey::<N>::size_in_bytes()
@@ -44,6 +44,8 @@ mod tests {
         ($poseidon:ident) => {{
             // Initialize Poseidon.
             let poseidon = $poseidon::<CurrentEnvironment>::setup("HashToGroupTest")?;
+            // Initialize Poseidon with a different domain.
+            let other_poseidon = $poseidon::<CurrentEnvironment>::setup("HashToGroupTestOther")?;
 
             // Ensure an empty input fails.
             assert!(poseidon.hash_to_group(&[]).is_err());
@@ -64,6 +66,11 @@ mod tests {
 
                     let candidate_cofactor_inv = candidate.div_by_cofactor();
                     assert_eq!(candidate, candidate_cofactor_inv.mul_by_cofactor());
+
+                    // Ensure the hash to group is deterministic.
+                    assert_eq!(candidate, poseidon.hash_to_group(&inputs)?);
+                    // Ensure the hash to group is separated by domain.
+                    assert_ne!(candidate, other_poseidon.hash_to_group(&inputs)?);
                 }
             }
             Ok(())
@@ -59,24 +59,32 @@ impl<E: Environment, const DEPTH: u8> MerklePath<E, DEPTH> {
         root: &PH::Hash,
         leaf: &LH::Leaf,
     ) -> bool {
-        // Ensure the leaf index is within the tree depth.
-        if (*self.leaf_index as u128) >= (1u128 << DEPTH) {
-            eprintln!("Found an out of bounds Merkle leaf index");
-            return false;
+        // Compute the root for the leaf, and ensure it matches the given root.
+        match self.compute_root(leaf_hasher, path_hasher, leaf) {
+            Ok(candidate_root) => candidate_root == *root,
+            Err(error) => {
+                eprintln!("{error}");
+                false
+            }
         }
+    }
+
+    /// Returns the Merkle root for the given leaf, by hashing the leaf up the path with its siblings.
+    pub fn compute_root<LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>>(
+        &self,
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        leaf: &LH::Leaf,
+    ) -> Result<PH::Hash> {
+        // Ensure the leaf index is within the tree depth.
+        ensure!((*self.leaf_index as u128) < (1u128 << DEPTH), "Found an out of bounds Merkle leaf index");
         // Ensure the path length matches the expected depth.
-        else if self.siblings.len() != DEPTH as usize {
-            eprintln!("Found an incorrect Merkle path length");
-            return false;
-        }
+        ensure!(self.siblings.len() == DEPTH as usize, "Found an incorrect Merkle path length");
 
         // Initialize a tracker for the current hash, by computing the leaf hash to start.
         let mut current_hash = match leaf_hasher.hash_leaf(leaf) {
             Ok(candidate_leaf_hash) => candidate_leaf_hash,
-            Err(error) => {
-                eprintln!("Failed to hash the Merkle leaf during verification: {error}");
-                return false;
-            }
+            Err(error) => bail!("Failed to hash the Merkle leaf: {error}"),
         };
 
         // Compute the ordering of the current hash and sibling hash on each level.
@@ -94,15 +102,12 @@ impl<E: Environment, const DEPTH: u8> MerklePath<E, DEPTH> {
             // Update the current hash for the next level.
             match path_hasher.hash_children(&left, &right) {
                 Ok(hash) => current_hash = hash,
-                Err(error) => {
-                    eprintln!("Failed to hash the Merkle path during verification: {error}");
-                    return false;
-                }
+                Err(error) => bail!("Failed to hash the Merkle path: {error}"),
             }
         }
 
-        // Ensure the final hash matches the given root.
-        current_hash == *root
+        // Return the computed root.
+        Ok(current_hash)
     }
 }
 
@@ -44,6 +44,13 @@ fn check_merkle_tree<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash
             let proof = merkle_tree.prove(leaf_index, leaf)?;
             // Verify the Merkle proof succeeds.
             assert!(proof.verify(leaf_hasher, path_hasher, merkle_tree.root(), leaf));
+            // Verify the Merkle proof computes the Merkle root.
+            assert_eq!(proof.compute_root(leaf_hasher, path_hasher, leaf)?, *merkle_tree.root());
+            // Verify the Merkle proof computes a different root for a different leaf.
+            let other_leaf = &leaves[(leaf_index + 1) % leaves.len()];
+            if leaf_hasher.hash_leaf(other_leaf)? != leaf_hasher.hash_leaf(leaf)? {
+                assert_ne!(proof.compute_root(leaf_hasher, path_hasher, other_leaf)?, *merkle_tree.root());
+            }
             // Verify the Merkle proof **fails** on an invalid root.
             assert!(!proof.verify(leaf_hasher, path_hasher, &PH::Hash::zero(), leaf));
             assert!(!proof.verify(leaf_hasher, path_hasher, &PH::Hash::one(), leaf));
@@ -107,6 +114,40 @@ fn check_merkle_tree_depth_2<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH:
     Ok(())
 }
 
+/// Runs the following test:
+/// 1. Construct a full depth-2 Merkle tree with 4 leaves.
+/// 2. Checks that appending an additional leaf fails, as the tree is at capacity.
+/// 3. Checks that the Merkle tree is not altered by the failed append.
+/// 4. Checks that constructing a depth-2 Merkle tree with 5 leaves fails.
+fn check_merkle_tree_depth_2_full<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>>(
+    leaf_hasher: &LH,
+    path_hasher: &PH,
+    leaves: &[LH::Leaf],
+    additional_leaves: &[LH::Leaf],
+) -> Result<()> {
+    assert_eq!(4, leaves.len(), "Full depth-2 test requires 4 leaves");
+    assert_eq!(1, additional_leaves.len(), "Full depth-2 test requires 1 additional leaf");
+
+    // Construct the Merkle tree for the given leaves.
+    let mut merkle_tree = MerkleTree::<E, LH, PH, 2>::new(leaf_hasher, path_hasher, leaves)?;
+    assert_eq!(4, merkle_tree.number_of_leaves);
+    let expected_root = *merkle_tree.root();
+
+    // Ensure appending beyond the capacity of the Merkle tree fails.
+    assert!(merkle_tree.prepare_append(additional_leaves).is_err());
+    assert!(merkle_tree.append(additional_leaves).is_err());
+
+    // Ensure the Merkle tree is unchanged.
+    assert_eq!(7, merkle_tree.tree.len());
+    assert_eq!(4, merkle_tree.number_of_leaves);
+    assert_eq!(expected_root, *merkle_tree.root());
+
+    // Ensure constructing a Merkle tree beyond its capacity fails.
+    let all_leaves = leaves.iter().chain(additional_leaves).cloned().collect::<Vec<_>>();
+    assert!(MerkleTree::<E, LH, PH, 2>::new(leaf_hasher, path_hasher, &all_leaves).is_err());
+    Ok(())
+}
+
 /// Runs the following test:
 /// 1. Construct a depth-3 Merkle tree with 4 leaves (leaving 4 leaves empty).
 /// 2. Checks that every node hash and the Merkle root is correct.
@@ -363,6 +404,41 @@ fn check_merkle_tree_depth_4_padded<E: Environment, LH: LeafHash<Hash = PH::Hash
     Ok(())
 }
 
+/// Runs the following test:
+/// 1. Construct the Merkle tree for the leaves.
+/// 2. Append the additional leaves in blocks of `block_size` leaves at a time.
+/// 3. Check that the resulting root matches the root of a Merkle tree built from all leaves at once.
+fn check_merkle_tree_incremental<
+    E: Environment,
+    LH: LeafHash<Hash = PH::Hash>,
+    PH: PathHash<Hash = Field<E>>,
+    const DEPTH: u8,
+>(
+    leaf_hasher: &LH,
+    path_hasher: &PH,
+    leaves: &[LH::Leaf],
+    additional_leaves: &[LH::Leaf],
+    block_size: usize,
+) -> Result<()> {
+    // Construct the Merkle tree for the given leaves.
+    let mut merkle_tree = MerkleTree::<E, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, leaves)?;
+
+    // Append the additional leaves one block at a time.
+    for block in additional_leaves.chunks(block_size) {
+        merkle_tree.append(block)?;
+    }
+    assert_eq!(leaves.len() + additional_leaves.len(), merkle_tree.number_of_leaves);
+
+    // Construct the expected Merkle tree from all of the leaves at once.
+    let all_leaves = leaves.iter().chain(additional_leaves).cloned().collect::<Vec<_>>();
+    let expected = MerkleTree::<E, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, &all_leaves)?;
+
+    // Ensure the incrementally-built Merkle tree matches the batch-built Merkle tree.
+    assert_eq!(expected.root(), merkle_tree.root());
+    assert_eq!(expected.tree(), merkle_tree.tree());
+    Ok(())
+}
+
 #[test]
 fn test_merkle_tree_bhp() -> Result<()> {
     fn run_test<const DEPTH: u8>(rng: &mut TestRng) -> Result<()> {
@@ -469,6 +545,31 @@ fn test_merkle_tree_poseidon() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_merkle_tree_incremental_poseidon() -> Result<()> {
+    type LH = Poseidon<CurrentEnvironment, 4>;
+    type PH = Poseidon<CurrentEnvironment, 2>;
+
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let mut rng = TestRng::default();
+
+    for num_leaves in [0, 1, 5, 16] {
+        for block_size in [1, 2, 3, 7] {
+            // Check the Merkle tree, appending 20 additional leaves in blocks.
+            check_merkle_tree_incremental::<CurrentEnvironment, LH, PH, 10>(
+                &leaf_hasher,
+                &path_hasher,
+                &(0..num_leaves).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>(),
+                &(0..20).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>(),
+                block_size,
+            )?;
+        }
+    }
+    Ok(())
+}
+
 #[test]
 fn test_merkle_tree_depth_2_bhp() -> Result<()> {
     type LH = BHP1024<CurrentEnvironment>;
@@ -505,6 +606,44 @@ fn test_merkle_tree_depth_2_poseidon() -> Result<()> {
     )
 }
 
+#[test]
+fn test_merkle_tree_depth_2_full_bhp() -> Result<()> {
+    type LH = BHP1024<CurrentEnvironment>;
+    type PH = BHP512<CurrentEnvironment>;
+
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let mut rng = TestRng::default();
+
+    // Check the full depth-2 Merkle tree.
+    check_merkle_tree_depth_2_full::<CurrentEnvironment, LH, PH>(
+        &leaf_hasher,
+        &path_hasher,
+        &(0..4).map(|_| Field::<CurrentEnvironment>::rand(&mut rng).to_bits_le()).collect::<Vec<Vec<bool>>>(),
+        &[Field::<CurrentEnvironment>::rand(&mut rng).to_bits_le()],
+    )
+}
+
+#[test]
+fn test_merkle_tree_depth_2_full_poseidon() -> Result<()> {
+    type LH = Poseidon<CurrentEnvironment, 4>;
+    type PH = Poseidon<CurrentEnvironment, 2>;
+
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let mut rng = TestRng::default();
+
+    // Check the full depth-2 Merkle tree.
+    check_merkle_tree_depth_2_full::<CurrentEnvironment, LH, PH>(
+        &leaf_hasher,
+        &path_hasher,
+        &(0..4).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>(),
+        &[vec![Uniform::rand(&mut rng)]],
+    )
+}
+
 #[test]
 fn test_merkle_tree_depth_3_bhp() -> Result<()> {
     type LH = BHP1024<CurrentEnvironment>;
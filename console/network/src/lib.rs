@@ -100,6 +100,8 @@ pub trait Network:
     const DEPLOYMENT_FEE_MULTIPLIER: u64 = 1_000; // 1 millicredit per byte
     /// The maximum number of microcredits that can be spent as a fee.
     const MAX_FEE: u64 = 1_000_000_000_000_000;
+    /// The weight of each proof byte in a block, relative to a plain data byte.
+    const PROOF_WEIGHT_MULTIPLIER: u64 = 4;
 
     /// The anchor height, defined as the expected number of blocks to reach the coinbase target.
     const ANCHOR_HEIGHT: u32 = Self::ANCHOR_TIME as u32 / Self::BLOCK_TIME as u32;
@@ -113,6 +115,8 @@ pub trait Network:
     const MAX_SOLUTIONS: usize = 1 << 8; // 256 solutions
     /// The number of blocks per epoch.
     const NUM_BLOCKS_PER_EPOCH: u32 = 3600 / Self::BLOCK_TIME as u32; // 360 blocks == ~1 hour
+    /// The block height from which a block timestamp must be after the median time past.
+    const MEDIAN_TIME_PAST_HEIGHT: u32 = u32::MAX; // Not yet activated
 
     /// The maximum number of entries in data.
     const MAX_DATA_ENTRIES: usize = 32;
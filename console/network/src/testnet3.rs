@@ -32,9 +32,12 @@ use snarkvm_console_algorithms::{
     BHP768,
 };
 
+/// The input domain message for the group bases of the Aleo signature and encryption schemes.
+const ACCOUNT_ENCRYPTION_AND_SIGNATURE_INPUT: &str = "AleoAccountEncryptionAndSignatureScheme0";
+
 lazy_static! {
     /// The group bases for the Aleo signature and encryption schemes.
-    pub static ref GENERATOR_G: Vec<Group<Testnet3>> = Testnet3::new_bases("AleoAccountEncryptionAndSignatureScheme0");
+    pub static ref GENERATOR_G: Vec<Group<Testnet3>> = Testnet3::new_bases(ACCOUNT_ENCRYPTION_AND_SIGNATURE_INPUT);
 
     /// The Varuna sponge parameters.
     pub static ref VARUNA_FS_PARAMETERS: FiatShamirParameters<Testnet3> = FiatShamir::<Testnet3>::sample_parameters();
@@ -494,4 +497,34 @@ mod tests {
         let group = CurrentNetwork::g_scalar_multiply(&scalar);
         assert_eq!(group, CurrentNetwork::g_powers()[0] * scalar);
     }
+
+    #[test]
+    fn test_new_bases_is_deterministic() {
+        // Run the setup for the account encryption and signature bases twice.
+        let first = CurrentNetwork::new_bases(ACCOUNT_ENCRYPTION_AND_SIGNATURE_INPUT);
+        let second = CurrentNetwork::new_bases(ACCOUNT_ENCRYPTION_AND_SIGNATURE_INPUT);
+        assert_eq!(first.len(), Scalar::<CurrentNetwork>::size_in_bits());
+
+        // Ensure both runs yield byte-identical generators.
+        for (a, b) in first.iter().zip_eq(&second) {
+            assert_eq!(a.to_bytes_le().unwrap(), b.to_bytes_le().unwrap());
+        }
+        // Ensure the generators match the network generators.
+        assert_eq!(first, *GENERATOR_G);
+    }
+
+    #[test]
+    fn test_varuna_universal_verifier_is_shared() {
+        // Fetch the universal verifier from several threads at once.
+        let addresses = (0..4)
+            .map(|_| std::thread::spawn(|| CurrentNetwork::varuna_universal_verifier() as *const _ as usize))
+            .collect::<Vec<_>>()
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect::<Vec<_>>();
+
+        // Ensure every thread received the same instance.
+        let expected = CurrentNetwork::varuna_universal_verifier() as *const _ as usize;
+        assert!(addresses.iter().all(|address| *address == expected));
+    }
 }
@@ -16,15 +16,19 @@ use super::*;
 
 impl<N: Network> Record<N, Ciphertext<N>> {
     /// Decrypts `self` into plaintext using the given view key and checks that the owner matches the view key.
+    /// Note: If the view key does not correspond to the record owner, this method fails before decrypting the record.
+    /// Any other error indicates the record ciphertext is malformed.
     pub fn decrypt(&self, view_key: &ViewKey<N>) -> Result<Record<N, Plaintext<N>>> {
         // Compute the record view key.
         let record_view_key = (self.nonce * **view_key).to_x_coordinate();
+        // Ensure the record owner matches the view key, before decrypting the record.
+        if !self.is_owner_with_record_view_key(&view_key.to_address(), &record_view_key) {
+            bail!("Illegal operation: Record::decrypt() view key does not correspond to the record owner.")
+        }
         // Decrypt the record.
-        let record = self.decrypt_symmetric_unchecked(&record_view_key)?;
-        // Ensure the record owner matches the view key.
-        match view_key.to_address() == **record.owner() {
-            true => Ok(record),
-            false => bail!("Illegal operation: Record::decrypt() view key does not correspond to the record owner."),
+        match self.decrypt_symmetric_unchecked(&record_view_key) {
+            Ok(record) => Ok(record),
+            Err(error) => bail!("Malformed record ciphertext: {error}"),
         }
     }
 
@@ -127,9 +131,45 @@ mod tests {
         let incorrect_private_key = PrivateKey::<N>::new(rng)?;
         // Generate a new view key.
         let incorrect_view_key = ViewKey::try_from(&incorrect_private_key)?;
-        // Ensure that decrypting with the incorrect view key fails.
-        assert!(ciphertext.decrypt(&incorrect_view_key).is_err());
+        // Ensure that decrypting with the incorrect view key fails, as the view key is not the owner.
+        let error = ciphertext.decrypt(&incorrect_view_key).unwrap_err();
+        assert!(error.to_string().contains("does not correspond to the record owner"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decrypt_malformed() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a view key and address.
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let view_key = ViewKey::try_from(&private_key)?;
+            let address = Address::try_from(&private_key)?;
+
+            // Compute the randomizer for the first private entry, given a public owner.
+            let nonce = CurrentNetwork::g_scalar_multiply(&Scalar::rand(&mut rng));
+            let record_view_key = (nonce * *view_key).to_x_coordinate();
+            let randomizers =
+                CurrentNetwork::hash_many_psd8(&[CurrentNetwork::encryption_domain(), record_view_key], 1);
+
+            // Prepare a record owned by the view key, with an entry that decrypts to an empty plaintext.
+            let ciphertext = Record::<CurrentNetwork, Ciphertext<CurrentNetwork>> {
+                owner: Owner::Public(address),
+                data: IndexMap::from_iter(vec![(
+                    Identifier::from_str("a")?,
+                    Entry::Private(Ciphertext::from_fields(&[randomizers[0] + Field::one()])?),
+                )]),
+                nonce,
+            };
 
+            // Ensure the record belongs to the view key.
+            assert!(ciphertext.is_owner(&view_key));
+            // Ensure that decrypting the record fails, as the ciphertext is malformed.
+            let error = ciphertext.decrypt(&view_key).unwrap_err();
+            assert!(error.to_string().starts_with("Malformed record ciphertext"));
+        }
         Ok(())
     }
 
@@ -14,3 +14,6 @@
 
 mod owner;
 pub use owner::*;
+
+mod serial_link_proof;
+pub use serial_link_proof::*;
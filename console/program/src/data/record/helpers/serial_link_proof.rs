@@ -0,0 +1,205 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Plaintext, Record};
+use snarkvm_console_account::ComputeKey;
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::{Address, Field, Group, Scalar};
+
+/// The domain separator for the serial link proof challenge.
+/// Note: This is distinct from the serial number domain, which is used to derive the generator `H`.
+const SERIAL_LINK_CHALLENGE_DOMAIN: &str = "AleoSerialLinkChallenge0";
+
+/// A proof that a serial number was derived from a record commitment by the owner of an address,
+/// without revealing the owner's private key.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SerialLinkProof<N: Network> {
+    /// The serial number generator `gamma := sk_sig * H`.
+    gamma: Group<N>,
+    /// The verifier challenge.
+    challenge: Scalar<N>,
+    /// The prover response.
+    response: Scalar<N>,
+    /// The compute key of the prover.
+    compute_key: ComputeKey<N>,
+}
+
+impl<N: Network> SerialLinkProof<N> {
+    /// Initializes a new serial link proof.
+    pub const fn new(gamma: Group<N>, challenge: Scalar<N>, response: Scalar<N>, compute_key: ComputeKey<N>) -> Self {
+        Self { gamma, challenge, response, compute_key }
+    }
+
+    /// Returns the serial number generator `gamma`.
+    pub const fn gamma(&self) -> Group<N> {
+        self.gamma
+    }
+
+    /// Returns the verifier challenge.
+    pub const fn challenge(&self) -> Scalar<N> {
+        self.challenge
+    }
+
+    /// Returns the prover response.
+    pub const fn response(&self) -> Scalar<N> {
+        self.response
+    }
+
+    /// Returns the compute key of the prover.
+    pub const fn compute_key(&self) -> ComputeKey<N> {
+        self.compute_key
+    }
+
+    /// Verifies (challenge == challenge') && (address == address') && (serial_number == serial_number') where:
+    ///     challenge' := HashToScalar(pk_sig, H, gamma, commitment, G^response pk_sig^challenge, H^response gamma^challenge)
+    ///     serial_number' := SerialNumber(gamma, commitment)
+    pub fn verify(&self, address: &Address<N>, commitment: Field<N>, serial_number: Field<N>) -> bool {
+        // Derive the serial number from `gamma`, and return `false` if this operation fails.
+        let candidate_serial_number = match Record::<N, Plaintext<N>>::serial_number_from_gamma(&self.gamma, commitment)
+        {
+            // Output the computed candidate serial number.
+            Ok(candidate_serial_number) => candidate_serial_number,
+            // Return `false` if the serial number errored.
+            Err(_) => return false,
+        };
+
+        // Compute the generator `H` as `HashToGroup(commitment)`, and return `false` if this operation fails.
+        let h = match N::hash_to_group_psd2(&[N::serial_number_domain(), commitment]) {
+            // Output the computed generator.
+            Ok(h) => h,
+            // Return `false` if the generator errored.
+            Err(_) => return false,
+        };
+
+        // Retrieve pk_sig.
+        let pk_sig = self.compute_key.pk_sig();
+
+        // Compute `g_r` := (response * G) + (challenge * pk_sig).
+        let g_r = N::g_scalar_multiply(&self.response) + (pk_sig * self.challenge);
+        // Compute `h_r` := (response * H) + (challenge * gamma).
+        let h_r = (h * self.response) + (self.gamma * self.challenge);
+
+        // Hash to derive the verifier challenge, and return `false` if this operation fails.
+        let candidate_challenge = match Self::compute_challenge(pk_sig, h, self.gamma, commitment, g_r, h_r) {
+            // Output the computed candidate challenge.
+            Ok(candidate_challenge) => candidate_challenge,
+            // Return `false` if the challenge errored.
+            Err(_) => return false,
+        };
+
+        // Derive the address from the compute key, and return `false` if this operation fails.
+        let candidate_address = match Address::try_from(self.compute_key) {
+            // Output the computed candidate address.
+            Ok(candidate_address) => candidate_address,
+            // Return `false` if the address errored.
+            Err(_) => return false,
+        };
+
+        // Return `true` if the candidate challenge, address, and serial number are correct.
+        self.challenge == candidate_challenge
+            && *address == candidate_address
+            && serial_number == candidate_serial_number
+    }
+
+    /// Returns the challenge as `HashToScalar(domain, pk_sig, H, gamma, commitment, g_r, h_r)`.
+    pub(crate) fn compute_challenge(
+        pk_sig: Group<N>,
+        h: Group<N>,
+        gamma: Group<N>,
+        commitment: Field<N>,
+        g_r: Group<N>,
+        h_r: Group<N>,
+    ) -> Result<Scalar<N>> {
+        N::hash_to_scalar_psd8(&[
+            Field::new_domain_separator(SERIAL_LINK_CHALLENGE_DOMAIN),
+            pk_sig.to_x_coordinate(),
+            h.to_x_coordinate(),
+            gamma.to_x_coordinate(),
+            commitment,
+            g_r.to_x_coordinate(),
+            h_r.to_x_coordinate(),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_account::PrivateKey;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+    type CurrentRecord = Record<CurrentNetwork, Plaintext<CurrentNetwork>>;
+
+    const ITERATIONS: u64 = 10;
+
+    #[test]
+    fn test_serial_link_proof() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a private key and a commitment.
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let address = Address::try_from(&private_key)?;
+            let commitment = Field::rand(&mut rng);
+
+            // Compute the serial number and its link proof.
+            let serial_number = CurrentRecord::serial_number(private_key, commitment)?;
+            let proof = CurrentRecord::prove_serial_link(&private_key, commitment, &mut rng)?;
+
+            // Ensure the proof verifies.
+            assert!(proof.verify(&address, commitment, serial_number));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_serial_link_proof_fails() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample a private key and a commitment.
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+        let address = Address::try_from(&private_key)?;
+        let commitment = Field::rand(&mut rng);
+
+        // Compute the serial number and its link proof.
+        let serial_number = CurrentRecord::serial_number(private_key, commitment)?;
+        let proof = CurrentRecord::prove_serial_link(&private_key, commitment, &mut rng)?;
+
+        // Ensure a mismatched serial number fails.
+        assert!(!proof.verify(&address, commitment, Field::rand(&mut rng)));
+        // Ensure a mismatched commitment fails.
+        assert!(!proof.verify(&address, Field::rand(&mut rng), serial_number));
+        // Ensure a mismatched address fails.
+        let other_address = Address::try_from(&PrivateKey::<CurrentNetwork>::new(&mut rng)?)?;
+        assert!(!proof.verify(&other_address, commitment, serial_number));
+        // Ensure a tampered response fails.
+        let tampered =
+            SerialLinkProof::new(proof.gamma(), proof.challenge(), Scalar::rand(&mut rng), proof.compute_key());
+        assert!(!tampered.verify(&address, commitment, serial_number));
+        // Ensure a tampered challenge fails.
+        let tampered =
+            SerialLinkProof::new(proof.gamma(), Scalar::rand(&mut rng), proof.response(), proof.compute_key());
+        assert!(!tampered.verify(&address, commitment, serial_number));
+        // Ensure a tampered serial number generator fails.
+        let tampered =
+            SerialLinkProof::new(Group::rand(&mut rng), proof.challenge(), proof.response(), proof.compute_key());
+        assert!(!tampered.verify(&address, commitment, serial_number));
+        // Ensure a tampered compute key fails.
+        let other_compute_key = ComputeKey::try_from(&PrivateKey::<CurrentNetwork>::new(&mut rng)?)?;
+        let tampered = SerialLinkProof::new(proof.gamma(), proof.challenge(), proof.response(), other_compute_key);
+        assert!(!tampered.verify(&address, commitment, serial_number));
+        Ok(())
+    }
+}
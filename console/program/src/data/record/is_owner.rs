@@ -55,6 +55,25 @@ impl<N: Network> Record<N, Ciphertext<N>> {
             }
         }
     }
+
+    /// Returns `true` if the given address is the record owner, using the given record view key to decrypt a private owner.
+    /// Note: This method does not check that the record view key corresponds to the address.
+    pub fn is_owner_with_record_view_key(&self, address: &Address<N>, record_view_key: &Field<N>) -> bool {
+        match &self.owner {
+            // If the owner is public, check if the address is the owner.
+            Owner::Public(owner) => owner == address,
+            // If the owner is private, decrypt the owner to check if it matches the address.
+            Owner::Private(ciphertext) => {
+                // Compute the 0th randomizer.
+                let randomizer = N::hash_many_psd8(&[N::encryption_domain(), *record_view_key], 1);
+                // Decrypt the owner.
+                let owner_x = ciphertext[0] - randomizer[0];
+                // Compare the x coordinates of computed and supplied addresses.
+                // Note: See `Self::is_owner_with_address_x_coordinate` for why this comparison suffices.
+                owner_x == address.to_x_coordinate()
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -90,6 +109,9 @@ mod tests {
 
         // Ensure the record belongs to the owner.
         assert!(ciphertext.is_owner(&view_key));
+        // Ensure the record belongs to the owner, given the record view key.
+        let record_view_key = (ciphertext.nonce * *view_key).to_x_coordinate();
+        assert!(ciphertext.is_owner_with_record_view_key(&view_key.to_address(), &record_view_key));
 
         // Sample a random view key and address.
         let private_key = PrivateKey::<N>::new(rng)?;
@@ -97,6 +119,9 @@ mod tests {
 
         // Ensure the random address is not the owner.
         assert!(!ciphertext.is_owner(&view_key));
+        // Ensure the random address is not the owner, given its record view key.
+        let record_view_key = (ciphertext.nonce * *view_key).to_x_coordinate();
+        assert!(!ciphertext.is_owner_with_record_view_key(&view_key.to_address(), &record_view_key));
 
         Ok(())
     }
@@ -25,6 +25,38 @@ impl<N: Network, Private: Visibility> Record<N, Private> {
         Self::serial_number_from_gamma(&gamma, commitment)
     }
 
+    /// Returns a proof that the serial number for the given commitment was derived by the owner of the private key,
+    /// without revealing the private key.
+    pub fn prove_serial_link<R: Rng + CryptoRng>(
+        private_key: &PrivateKey<N>,
+        commitment: Field<N>,
+        rng: &mut R,
+    ) -> Result<SerialLinkProof<N>> {
+        // Retrieve `sk_sig`.
+        let sk_sig = private_key.sk_sig();
+        // Derive the compute key.
+        let compute_key = ComputeKey::try_from(private_key)?;
+
+        // Compute the generator `H` as `HashToGroup(commitment)`.
+        let h = N::hash_to_group_psd2(&[N::serial_number_domain(), commitment])?;
+        // Compute `gamma` as `sk_sig * H`.
+        let gamma = h * sk_sig;
+
+        // Sample a random nonce.
+        let r = Scalar::<N>::rand(rng);
+        // Compute `g_r` as `r * G`.
+        let g_r = N::g_scalar_multiply(&r);
+        // Compute `h_r` as `r * H`.
+        let h_r = h * r;
+
+        // Compute `challenge` as `HashToScalar(pk_sig, H, gamma, commitment, g_r, h_r)`.
+        let challenge = SerialLinkProof::compute_challenge(compute_key.pk_sig(), h, gamma, commitment, g_r, h_r)?;
+        // Compute `response` as `r - challenge * sk_sig`.
+        let response = r - challenge * sk_sig;
+
+        Ok(SerialLinkProof::new(gamma, challenge, response, compute_key))
+    }
+
     /// A helper method to derive the serial number from the gamma and commitment.
     pub fn serial_number_from_gamma(gamma: &Group<N>, commitment: Field<N>) -> Result<Field<N>> {
         // Compute `sn_nonce` as `Hash(COFACTOR * gamma)`.
@@ -39,6 +39,9 @@ pub use locator::*;
 mod owner;
 pub use owner::*;
 
+#[cfg(any(test, feature = "prop-tests"))]
+pub mod prop_tests;
+
 mod request;
 pub use request::*;
 
@@ -0,0 +1,99 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_console_account::PrivateKey;
+#[cfg(test)]
+use snarkvm_console_account::ViewKey;
+
+use indexmap::IndexMap;
+use proptest::{
+    collection::vec,
+    prelude::{BoxedStrategy, Strategy, any},
+};
+use rand::SeedableRng;
+use test_strategy::proptest;
+
+type CurrentNetwork = snarkvm_console_network::Testnet3;
+
+/// Returns a strategy for a record with a random owner, `u64` entries of every visibility, and a random nonce.
+pub fn any_record() -> BoxedStrategy<Record<CurrentNetwork, Plaintext<CurrentNetwork>>> {
+    any_record_with_keys().prop_map(|(_, _, record)| record).boxed()
+}
+
+/// A record, along with the private key of its owner and the randomizer of its nonce.
+pub type RecordWithKeys =
+    (PrivateKey<CurrentNetwork>, Scalar<CurrentNetwork>, Record<CurrentNetwork, Plaintext<CurrentNetwork>>);
+
+/// Returns a strategy for a record, along with the private key of its owner and the randomizer of its nonce.
+pub fn any_record_with_keys() -> BoxedStrategy<RecordWithKeys> {
+    (any::<u64>(), any::<bool>(), vec((any::<u64>(), 0..3u8), 0..=CurrentNetwork::MAX_DATA_ENTRIES))
+        .prop_map(|(seed, is_owner_public, entries)| {
+            let rng = &mut rand_chacha::ChaChaRng::seed_from_u64(seed);
+            // Sample the owner.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+            let address = Address::try_from(private_key).unwrap();
+            let owner = match is_owner_public {
+                true => Owner::Public(address),
+                false => Owner::Private(Plaintext::from(Literal::Address(address))),
+            };
+            // Construct the entries.
+            let data = entries
+                .into_iter()
+                .enumerate()
+                .map(|(index, (value, mode))| {
+                    let name = Identifier::from_str(&format!("entry_{index}")).unwrap();
+                    let plaintext = Plaintext::from(Literal::U64(U64::new(value)));
+                    let entry = match mode {
+                        0 => Entry::Constant(plaintext),
+                        1 => Entry::Public(plaintext),
+                        _ => Entry::Private(plaintext),
+                    };
+                    (name, entry)
+                })
+                .collect::<IndexMap<_, _>>();
+            // Sample the randomizer, and compute the nonce.
+            let randomizer = Scalar::rand(rng);
+            let nonce = CurrentNetwork::g_scalar_multiply(&randomizer);
+            // Construct the record.
+            let record =
+                Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_plaintext(owner, data, nonce).unwrap();
+            (private_key, randomizer, record)
+        })
+        .boxed()
+}
+
+#[proptest]
+fn record_bytes_round_trip(#[strategy(any_record())] record: Record<CurrentNetwork, Plaintext<CurrentNetwork>>) {
+    let bytes = record.to_bytes_le().unwrap();
+    let candidate = Record::read_le(&bytes[..]).unwrap();
+    assert_eq!(record, candidate);
+    // Ensure the byte encoding is deterministic.
+    assert_eq!(bytes, candidate.to_bytes_le().unwrap());
+}
+
+#[proptest]
+fn record_encrypt_round_trip(#[strategy(any_record_with_keys())] input: RecordWithKeys, seed: u64) {
+    let (private_key, randomizer, record) = input;
+    let ciphertext = record.encrypt(randomizer).unwrap();
+    // Ensure the owner recovers the record.
+    assert_eq!(record, ciphertext.decrypt(&ViewKey::try_from(private_key).unwrap()).unwrap());
+
+    // Ensure an unrelated view key fails to decrypt the record.
+    let rng = &mut rand_chacha::ChaChaRng::seed_from_u64(seed);
+    let other_view_key = ViewKey::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+    if other_view_key != ViewKey::try_from(private_key).unwrap() {
+        assert!(ciphertext.decrypt(&other_view_key).is_err());
+    }
+}
@@ -53,4 +53,24 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_bytes_and_string_agree() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new address.
+            let expected = Address::<CurrentEnvironment>::rand(&mut rng);
+
+            // Ensure the address recovered from its string matches the address recovered from its bytes.
+            let from_string = Address::<CurrentEnvironment>::from_str(&expected.to_string())?;
+            let from_bytes = Address::<CurrentEnvironment>::read_le(&expected.to_bytes_le()?[..])?;
+            assert_eq!(from_string, from_bytes);
+            assert_eq!(expected, from_string);
+            // Ensure both forms re-encode identically.
+            assert_eq!(from_string.to_bytes_le()?, from_bytes.to_bytes_le()?);
+            assert_eq!(from_string.to_string(), from_bytes.to_string());
+        }
+        Ok(())
+    }
 }
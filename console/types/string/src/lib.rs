@@ -48,6 +48,27 @@ impl<E: Environment> StringType<E> {
             false => E::halt(format!("Attempted to allocate a string of size {num_bytes}")),
         }
     }
+
+    /// Initializes a new string, returning an error if it exceeds `E::MAX_STRING_BYTES` bytes.
+    pub fn try_new(string: &str) -> Result<Self> {
+        // Ensure the string is within the allowed capacity.
+        let num_bytes = string.len();
+        match num_bytes <= E::MAX_STRING_BYTES as usize {
+            true => Ok(Self { string: string.to_string(), _phantom: PhantomData }),
+            false => bail!("String exceeds the maximum length of {} bytes, found {num_bytes}", E::MAX_STRING_BYTES),
+        }
+    }
+
+    /// Initializes a new string, truncating it to at most `E::MAX_STRING_BYTES` bytes.
+    /// Note: The string is truncated at a character boundary, so it may be shorter than the maximum length.
+    pub fn new_truncated(string: &str) -> Self {
+        // Find the last character boundary within the allowed capacity.
+        let mut num_bytes = string.len().min(E::MAX_STRING_BYTES as usize);
+        while !string.is_char_boundary(num_bytes) {
+            num_bytes -= 1;
+        }
+        Self { string: string[..num_bytes].to_string(), _phantom: PhantomData }
+    }
 }
 
 impl<E: Environment> TypeName for StringType<E> {
@@ -66,3 +87,43 @@ impl<E: Environment> Deref for StringType<E> {
         self.string.as_str()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const MAX_STRING_BYTES: usize = CurrentEnvironment::MAX_STRING_BYTES as usize;
+
+    #[test]
+    fn test_try_new() {
+        // Ensure strings within the maximum length are accepted.
+        for num_bytes in [0, 1, MAX_STRING_BYTES] {
+            let string = "a".repeat(num_bytes);
+            assert_eq!(StringType::<CurrentEnvironment>::try_new(&string).unwrap(), StringType::new(&string));
+        }
+        // Ensure a string over the maximum length is rejected.
+        assert!(StringType::<CurrentEnvironment>::try_new(&"a".repeat(MAX_STRING_BYTES + 1)).is_err());
+    }
+
+    #[test]
+    fn test_new_truncated() {
+        // Ensure strings within the maximum length are unchanged.
+        for num_bytes in [0, 1, MAX_STRING_BYTES] {
+            let string = "a".repeat(num_bytes);
+            assert_eq!(*StringType::<CurrentEnvironment>::new_truncated(&string), string);
+        }
+        // Ensure a string over the maximum length is truncated to the maximum length.
+        let string = "a".repeat(MAX_STRING_BYTES + 1);
+        assert_eq!(*StringType::<CurrentEnvironment>::new_truncated(&string), string[..MAX_STRING_BYTES]);
+
+        // Ensure a multi-byte character straddling the maximum length is dropped.
+        let string = format!("{}é", "a".repeat(MAX_STRING_BYTES - 1));
+        let candidate = StringType::<CurrentEnvironment>::new_truncated(&string);
+        assert_eq!(*candidate, string[..MAX_STRING_BYTES - 1]);
+        // Ensure the truncated string round-trips through its byte representation.
+        assert_eq!(candidate, StringType::read_le(&candidate.to_bytes_le().unwrap()[..]).unwrap());
+    }
+}
@@ -14,25 +14,49 @@
 
 use super::*;
 
-impl<N: Network> FromBytes for Block<N> {
-    /// Reads the block from the buffer.
-    #[inline]
-    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
-        // Read the version.
-        let version = u8::read_le(&mut reader)?;
-        // Ensure the version is valid.
-        if version != 1 {
-            return Err(error("Invalid block version"));
-        }
+impl<N: Network> Block<N> {
+    /// Returns the header bytes of the block, consisting of the block hash, previous block hash, and header.
+    pub fn header_bytes(&self) -> Result<Vec<u8>> {
+        let mut bytes = Vec::new();
+        self.write_header_le(&mut bytes)?;
+        Ok(bytes)
+    }
 
+    /// Returns the body bytes of the block, consisting of the authority, ratifications, solutions,
+    /// transactions, and aborted transaction IDs.
+    pub fn body_bytes(&self) -> Result<Vec<u8>> {
+        let mut bytes = Vec::new();
+        self.write_body_le(&mut bytes)?;
+        Ok(bytes)
+    }
+
+    /// Returns the block from the given header bytes and body bytes.
+    pub fn from_header_and_body(mut header_bytes: &[u8], mut body_bytes: &[u8]) -> Result<Self> {
+        // Read the header.
+        let (block_hash, previous_hash, header) = Self::read_header_le(&mut header_bytes)?;
+        ensure!(header_bytes.is_empty(), "Found {} trailing bytes in the block header bytes", header_bytes.len());
+        // Read the body.
+        let block = Self::read_body_le(&mut body_bytes, previous_hash, header)?;
+        ensure!(body_bytes.is_empty(), "Found {} trailing bytes in the block body bytes", body_bytes.len());
+        // Ensure the block hash matches.
+        ensure!(block_hash == block.hash(), "The block header does not correspond to the block body");
+        Ok(block)
+    }
+
+    /// Reads the block hash, previous block hash, and header from the buffer.
+    fn read_header_le<R: Read>(mut reader: R) -> IoResult<(N::BlockHash, N::BlockHash, Header<N>)> {
         // Read the block hash.
-        let block_hash: N::BlockHash = FromBytes::read_le(&mut reader)?;
+        let block_hash = FromBytes::read_le(&mut reader)?;
         // Read the previous block hash.
         let previous_hash = FromBytes::read_le(&mut reader)?;
         // Read the header.
         let header = FromBytes::read_le(&mut reader)?;
+        Ok((block_hash, previous_hash, header))
+    }
 
-        // Write the authority.
+    /// Reads the block body from the buffer, and constructs the block with the given previous block hash and header.
+    fn read_body_le<R: Read>(mut reader: R, previous_hash: N::BlockHash, header: Header<N>) -> IoResult<Self> {
+        // Read the authority.
         let authority = FromBytes::read_le(&mut reader)?;
 
         // Read the number of ratifications.
@@ -62,39 +86,22 @@ impl<N: Network> FromBytes for Block<N> {
         }
 
         // Construct the block.
-        let block = Self::from(
-            previous_hash,
-            header,
-            authority,
-            ratifications,
-            solutions,
-            transactions,
-            aborted_transaction_ids,
-        )
-        .map_err(error)?;
-
-        // Ensure the block hash matches.
-        match block_hash == block.hash() {
-            true => Ok(block),
-            false => Err(error("Mismatching block hash, possible data corruption")),
-        }
+        Self::from(previous_hash, header, authority, ratifications, solutions, transactions, aborted_transaction_ids)
+            .map_err(error)
     }
-}
-
-impl<N: Network> ToBytes for Block<N> {
-    /// Writes the block to the buffer.
-    #[inline]
-    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
-        // Write the version.
-        1u8.write_le(&mut writer)?;
 
+    /// Writes the block hash, previous block hash, and header to the buffer.
+    fn write_header_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         // Write the block hash.
         self.block_hash.write_le(&mut writer)?;
         // Write the previous block hash.
         self.previous_hash.write_le(&mut writer)?;
         // Write the header.
-        self.header.write_le(&mut writer)?;
+        self.header.write_le(&mut writer)
+    }
 
+    /// Writes the block body to the buffer.
+    fn write_body_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         // Write the authority.
         self.authority.write_le(&mut writer)?;
 
@@ -119,6 +126,44 @@ impl<N: Network> ToBytes for Block<N> {
     }
 }
 
+impl<N: Network> FromBytes for Block<N> {
+    /// Reads the block from the buffer.
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid block version"));
+        }
+
+        // Read the block hash, previous block hash, and header.
+        let (block_hash, previous_hash, header) = Self::read_header_le(&mut reader)?;
+        // Read the body, and construct the block.
+        let block = Self::read_body_le(&mut reader, previous_hash, header)?;
+
+        // Ensure the block hash matches.
+        match block_hash == block.hash() {
+            true => Ok(block),
+            false => Err(error("Mismatching block hash, possible data corruption")),
+        }
+    }
+}
+
+impl<N: Network> ToBytes for Block<N> {
+    /// Writes the block to the buffer.
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+
+        // Write the block hash, previous block hash, and header.
+        self.write_header_le(&mut writer)?;
+        // Write the body.
+        self.write_body_le(&mut writer)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -134,6 +179,8 @@ mod tests {
             // Check the byte representation.
             let expected_bytes = expected.to_bytes_le()?;
             assert_eq!(expected, Block::read_le(&expected_bytes[..])?);
+            // Check the size in bytes.
+            assert_eq!(expected.size_in_bytes()?, u64::try_from(expected_bytes.len())?);
         }
         Ok(())
     }
@@ -146,7 +193,37 @@ mod tests {
         // Check the byte representation.
         let expected_bytes = genesis_block.to_bytes_le()?;
         assert_eq!(genesis_block, Block::read_le(&expected_bytes[..])?);
+        // Check the size in bytes.
+        assert_eq!(genesis_block.size_in_bytes()?, u64::try_from(expected_bytes.len())?);
+
+        Ok(())
+    }
 
+    #[test]
+    fn test_header_and_body_bytes() -> Result<()> {
+        // Load the genesis block.
+        let genesis_block = Block::<CurrentNetwork>::read_le(CurrentNetwork::genesis_bytes())?;
+
+        // Split the block into its header and body.
+        let header_bytes = genesis_block.header_bytes()?;
+        let body_bytes = genesis_block.body_bytes()?;
+        // Ensure the block bytes are the version, followed by the header and body.
+        assert_eq!([&[1u8][..], &header_bytes[..], &body_bytes[..]].concat(), genesis_block.to_bytes_le()?);
+
+        // Ensure recombining the header and body yields the block.
+        assert_eq!(genesis_block, Block::from_header_and_body(&header_bytes, &body_bytes)?);
+
+        // Ensure trailing bytes are rejected.
+        assert!(
+            Block::<CurrentNetwork>::from_header_and_body(&[&header_bytes[..], &[0u8]].concat(), &body_bytes).is_err()
+        );
+        assert!(
+            Block::<CurrentNetwork>::from_header_and_body(&header_bytes, &[&body_bytes[..], &[0u8]].concat()).is_err()
+        );
+        // Ensure a header from another block is rejected.
+        let rng = &mut TestRng::default();
+        let other_header_bytes = crate::test_helpers::sample_genesis_block(rng).header_bytes()?;
+        assert!(Block::<CurrentNetwork>::from_header_and_body(&other_header_bytes, &body_bytes).is_err());
         Ok(())
     }
 }
@@ -54,4 +54,16 @@ mod tests {
         let genesis_block = Block::<CurrentNetwork>::read_le(CurrentNetwork::genesis_bytes()).unwrap();
         assert!(genesis_block.is_genesis());
     }
+
+    #[test]
+    fn test_genesis_corrupted() {
+        // Flip a byte in the block hash, and a byte in the block header.
+        // Note: The block hash starts after the 1-byte version, and the header after the two 32-byte hashes.
+        for index in [1, 1 + 32 + 32 + 8] {
+            let mut genesis_bytes = CurrentNetwork::genesis_bytes().to_vec();
+            genesis_bytes[index] ^= 1;
+            // Ensure the corrupted genesis block fails to load.
+            assert!(Block::<CurrentNetwork>::read_le(&genesis_bytes[..]).is_err());
+        }
+    }
 }
@@ -16,6 +16,8 @@ use console::prelude::{ensure, Result};
 
 /// A safety bound (sanity-check) for the coinbase reward.
 pub const MAX_COINBASE_REWARD: u64 = 190_258_739; // Coinbase reward at block 1.
+/// The number of latest block timestamps used to compute the median time past.
+pub const MEDIAN_TIME_PAST_WINDOW: u32 = 11;
 
 /// Calculate the block reward, given the total supply, block time, coinbase reward, and transaction fees.
 ///     R_staking = floor((0.05 * S) / H_Y1) + CR / 2 + TX_F.
@@ -136,6 +138,28 @@ pub fn proof_target(coinbase_target: u64, genesis_proof_target: u64) -> u64 {
     coinbase_target.checked_shr(7).map(|target| target.saturating_add(1)).unwrap_or(genesis_proof_target)
 }
 
+/// Returns the median of the given block timestamps, or `None` if no timestamps are given.
+/// For an even number of timestamps, the upper median is returned.
+pub fn median_time_past(timestamps: &[i64]) -> Option<i64> {
+    // Sort the timestamps.
+    let mut timestamps = timestamps.to_vec();
+    timestamps.sort_unstable();
+    // Return the median timestamp.
+    timestamps.get(timestamps.len() / 2).copied()
+}
+
+/// Returns the mean interval in seconds between consecutive block timestamps, given in block order,
+/// or `None` if fewer than two timestamps are given.
+pub fn average_block_time(timestamps: &[i64]) -> Option<f64> {
+    match (timestamps.first(), timestamps.last()) {
+        // Note: The sum of the consecutive intervals is the interval between the first and last timestamps.
+        (Some(first), Some(last)) if timestamps.len() > 1 => {
+            Some((*last as f64 - *first as f64) / (timestamps.len() - 1) as f64)
+        }
+        _ => None,
+    }
+}
+
 /// Retarget algorithm using fixed point arithmetic from https://www.reference.cash/protocol/forks/2020-11-15-asert.
 ///     T_{i+1} = T_i * 2^(INV * (D - A) / TAU).
 ///     T_i = Current target.
@@ -202,15 +226,17 @@ fn retarget(
     // Calculate the new difficulty.
     // Shift the target to multiply by 2^(integer) / RADIX.
     let shifts = integral - RBITS as i128;
+    // Note: A shift that does not fit in a `u32` (i.e. an extreme drift) saturates the target.
     let mut candidate_target = if shifts < 0 {
-        match candidate_target.checked_shr(u32::try_from(-shifts)?) {
+        match u32::try_from(-shifts).ok().and_then(|shifts| candidate_target.checked_shr(shifts)) {
             Some(target) => core::cmp::max(target, 1),
             None => 1,
         }
     } else {
-        match candidate_target.checked_shl(u32::try_from(shifts)?) {
-            Some(target) => core::cmp::max(target, 1),
-            None => u64::MAX as u128,
+        // Note: A left shift that would drop any set bit of the target saturates the target.
+        match u32::try_from(shifts) {
+            Ok(shifts) if shifts < candidate_target.leading_zeros() => core::cmp::max(candidate_target << shifts, 1),
+            _ => u64::MAX as u128,
         }
     };
 
@@ -236,6 +262,36 @@ mod tests {
     const EXPECTED_STAKING_REWARD: u64 = 23_782_343;
     const EXPECTED_COINBASE_REWARD_AT_BLOCK_1: u64 = MAX_COINBASE_REWARD;
 
+    #[test]
+    fn test_median_time_past() {
+        // Ensure no timestamps have no median.
+        assert_eq!(median_time_past(&[]), None);
+        // Ensure a single timestamp is its own median.
+        assert_eq!(median_time_past(&[5]), Some(5));
+        // Ensure the median of an out-of-order window is computed.
+        let timestamps = [10, 60, 20, 30, 110, 40, 50, 70, 100, 80, 90];
+        assert_eq!(timestamps.len(), MEDIAN_TIME_PAST_WINDOW as usize);
+        assert_eq!(median_time_past(&timestamps), Some(60));
+        // Ensure an outlier timestamp does not move the median.
+        let timestamps = [10, 20, 30, 40, 50, 60, 70, 80, 90, 100, i64::MAX];
+        assert_eq!(median_time_past(&timestamps), Some(60));
+        // Ensure the upper median is returned for an even number of timestamps.
+        assert_eq!(median_time_past(&[1, 2, 3, 4]), Some(3));
+    }
+
+    #[test]
+    fn test_average_block_time() {
+        // Ensure fewer than two timestamps have no average block time.
+        assert_eq!(average_block_time(&[]), None);
+        assert_eq!(average_block_time(&[5]), None);
+        // Ensure a chain with a constant block time returns it.
+        let timestamps = (0..10).map(|i| 1_000 + i * 15).collect::<Vec<_>>();
+        assert_eq!(average_block_time(&timestamps), Some(15.0));
+        // Ensure a chain with varying block times returns the mean interval.
+        assert_eq!(average_block_time(&[100, 110, 140, 145]), Some(15.0));
+        assert_eq!(average_block_time(&[0, 1, 3]), Some(1.5));
+    }
+
     #[test]
     fn test_anchor_block_reward() {
         let reward = anchor_block_reward_at_height(
@@ -599,6 +655,57 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_retarget_timestamp_edge_cases() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let previous_target: u64 = rng.gen_range(1..u64::MAX);
+            let previous_timestamp: i64 = rng.gen_range(0..i64::MAX / 2);
+
+            // Computes the coinbase target for the given block timestamp.
+            let target = |timestamp: i64| {
+                coinbase_target(
+                    previous_target,
+                    previous_timestamp,
+                    timestamp,
+                    CurrentNetwork::ANCHOR_TIME,
+                    CurrentNetwork::NUM_BLOCKS_PER_EPOCH,
+                    1,
+                )
+                .unwrap()
+            };
+
+            // Ensure equal timestamps are treated as one second elapsed.
+            let equal = target(previous_timestamp);
+            assert_eq!(equal, target(previous_timestamp + 1));
+            // Ensure a timestamp in the past is treated as one second elapsed.
+            assert_eq!(equal, target(previous_timestamp - 1));
+            assert_eq!(equal, target(i64::MIN));
+            // Ensure an extreme gap saturates, rather than failing or returning zero.
+            let extreme = target(i64::MAX);
+            assert!(extreme >= 1);
+            assert!(extreme <= previous_target);
+        }
+
+        // Ensure an extreme gap saturates in the non-inverse direction.
+        for previous_target in [1, u64::MAX / 2, u64::MAX] {
+            let candidate = retarget(previous_target, 0, i64::MAX, CurrentNetwork::ANCHOR_TIME, 1, false).unwrap();
+            assert_eq!(candidate, u64::MAX);
+            let candidate =
+                retarget(previous_target, i64::MIN, i64::MAX, CurrentNetwork::ANCHOR_TIME, 1, true).unwrap();
+            assert_eq!(candidate, 1);
+        }
+
+        // Ensure a left shift that would drop the high bits of the target saturates, rather than wrapping.
+        let anchor_time = CurrentNetwork::ANCHOR_TIME;
+        let candidate = retarget(1 << 40, 0, anchor_time as i64 + 116, anchor_time, 1, false).unwrap();
+        assert_eq!(candidate, u64::MAX);
+        // Ensure a left shift that keeps every bit of the target is not saturated.
+        let candidate = retarget(1, 0, anchor_time as i64 + 32, anchor_time, 1, false).unwrap();
+        assert_eq!(candidate, 1 << 32);
+    }
+
     #[test]
     fn test_target_halving() {
         let mut rng = TestRng::default();
@@ -43,6 +43,7 @@ mod genesis;
 mod serialize;
 mod string;
 mod verify;
+mod weight;
 
 use console::{
     account::PrivateKey,
@@ -255,6 +256,13 @@ impl<N: Network> Block<N> {
     }
 }
 
+impl<N: Network> Block<N> {
+    /// Returns the size in bytes.
+    pub fn size_in_bytes(&self) -> Result<u64> {
+        Ok(u64::try_from(self.to_bytes_le()?.len())?)
+    }
+}
+
 impl<N: Network> Block<N> {
     /// Returns the block header.
     pub const fn header(&self) -> &Header<N> {
@@ -798,4 +806,23 @@ mod tests {
             assert_eq!(transaction.find_record(commitment), None);
         }
     }
+
+    #[test]
+    fn test_from_rejects_empty_transactions() {
+        let rng = &mut TestRng::default();
+
+        let (block, _) = crate::test_helpers::sample_genesis_block_and_transaction(rng);
+
+        // Ensure a block cannot be initialized without transactions.
+        let result = Block::from(
+            block.previous_hash(),
+            *block.header(),
+            block.authority().clone(),
+            block.ratifications().clone(),
+            block.solutions().cloned(),
+            Transactions::from_iter(std::iter::empty::<ConfirmedTransaction<_>>()),
+            vec![],
+        );
+        assert!(result.is_err());
+    }
 }
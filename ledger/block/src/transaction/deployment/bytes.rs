@@ -87,6 +87,8 @@ mod tests {
         // Check the byte representation.
         let expected_bytes = expected.to_bytes_le()?;
         assert_eq!(expected, Deployment::read_le(&expected_bytes[..])?);
+        // Check the size in bytes.
+        assert_eq!(expected.size_in_bytes()?, u64::try_from(expected_bytes.len())?);
         Ok(())
     }
 }
@@ -0,0 +1,112 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Transaction<N> {
+    /// Returns the names of the components that differ between this transaction and the given transaction.
+    /// This is intended as a debugging aid, for when two transactions are expected to be equal.
+    pub fn diff(&self, other: &Self) -> Vec<String> {
+        let mut differences = Vec::new();
+
+        // Compare the transaction IDs.
+        if self.id() != other.id() {
+            differences.push("id".to_string());
+        }
+
+        match (self, other) {
+            (Self::Deploy(_, owner, deployment, fee), Self::Deploy(_, other_owner, other_deployment, other_fee)) => {
+                if owner != other_owner {
+                    differences.push("owner".to_string());
+                }
+                if deployment != other_deployment {
+                    differences.push("deployment".to_string());
+                }
+                if fee != other_fee {
+                    differences.push("fee".to_string());
+                }
+            }
+            (Self::Execute(_, execution, fee), Self::Execute(_, other_execution, other_fee)) => {
+                if execution.global_state_root() != other_execution.global_state_root() {
+                    differences.push("global state root".to_string());
+                }
+                if execution.proof() != other_execution.proof() {
+                    differences.push("proof".to_string());
+                }
+                if execution.len() != other_execution.len() {
+                    differences.push("number of transitions".to_string());
+                }
+                for (index, (transition, other_transition)) in
+                    execution.transitions().zip(other_execution.transitions()).enumerate()
+                {
+                    if transition != other_transition {
+                        differences.push(format!("transition {index}"));
+                    }
+                }
+                if fee != other_fee {
+                    differences.push("fee".to_string());
+                }
+            }
+            (Self::Fee(_, fee), Self::Fee(_, other_fee)) => {
+                if fee != other_fee {
+                    differences.push("fee".to_string());
+                }
+            }
+            _ => differences.push("type".to_string()),
+        }
+
+        differences
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_diff() -> Result<()> {
+        // Load the genesis transactions.
+        let block = crate::Block::<CurrentNetwork>::read_le(CurrentNetwork::genesis_bytes())?;
+        let transactions = block.transactions().iter().map(|transaction| transaction.transaction()).collect::<Vec<_>>();
+        let (first, second) = (transactions[0], transactions[1]);
+
+        // Ensure a transaction does not differ from itself.
+        assert!(first.diff(first).is_empty());
+        assert!(first.diff(&first.clone()).is_empty());
+
+        // Ensure a copy with a modified ID reports only the ID.
+        let (Transaction::Execute(_, execution, fee), Transaction::Execute(other_id, other_execution, _)) =
+            (first, second)
+        else {
+            panic!("Expected the genesis transactions to be executions")
+        };
+        let candidate = Transaction::Execute(*other_id, execution.clone(), fee.clone());
+        assert_eq!(first.diff(&candidate), vec!["id"]);
+
+        // Ensure a copy with a modified execution reports only the execution components.
+        let candidate = Transaction::Execute(first.id(), other_execution.clone(), fee.clone());
+        assert_eq!(first.diff(&candidate), vec!["proof", "transition 0"]);
+
+        // Ensure different transaction types are reported.
+        let candidate = Transaction::Fee(
+            first.id(),
+            Fee::from_unchecked(execution.peek()?.clone(), execution.global_state_root(), None),
+        );
+        assert_eq!(first.diff(&candidate), vec!["type"]);
+        Ok(())
+    }
+}
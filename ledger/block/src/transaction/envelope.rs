@@ -0,0 +1,114 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The version of the transaction JSON envelope.
+const ENVELOPE_VERSION: u64 = 1;
+
+impl<N: Network> Transaction<N> {
+    /// Returns the transaction as a JSON envelope of the form
+    /// `{ "version": 1, "network": N::ID, "transaction": { ... } }`.
+    pub fn to_json_envelope(&self) -> Result<String> {
+        // Construct the envelope.
+        let mut envelope = serde_json::Map::new();
+        envelope.insert("version".to_string(), ENVELOPE_VERSION.into());
+        envelope.insert("network".to_string(), N::ID.into());
+        envelope.insert("transaction".to_string(), serde_json::to_value(self)?);
+        // Serialize the envelope.
+        Ok(serde_json::Value::Object(envelope).to_string())
+    }
+
+    /// Returns the transaction from a JSON envelope, ensuring the envelope version and network ID match.
+    pub fn from_json_envelope(envelope: &str) -> Result<Self> {
+        // Deserialize the envelope into a JSON value.
+        let mut envelope: serde_json::Value = serde_json::from_str(envelope)?;
+
+        // Ensure the envelope version matches.
+        match envelope.get("version").and_then(|version| version.as_u64()) {
+            Some(ENVELOPE_VERSION) => (),
+            Some(version) => {
+                bail!("Invalid transaction envelope version: found {version}, expected {ENVELOPE_VERSION}")
+            }
+            None => bail!("The \"version\" field is missing from the transaction envelope"),
+        }
+        // Ensure the network ID matches.
+        match envelope.get("network").and_then(|network| network.as_u64()) {
+            Some(network) if network == u64::from(N::ID) => (),
+            Some(network) => bail!("Invalid transaction envelope network: found {network}, expected {}", N::ID),
+            None => bail!("The \"network\" field is missing from the transaction envelope"),
+        }
+
+        // Retrieve the transaction.
+        match envelope.get_mut("transaction") {
+            Some(transaction) => Ok(serde_json::from_value(transaction.take())?),
+            None => bail!("The \"transaction\" field is missing from the transaction envelope"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_json_envelope() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for expected in [
+            crate::transaction::test_helpers::sample_deployment_transaction(true, rng),
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng),
+        ]
+        .into_iter()
+        {
+            // Ensure the transaction round-trips through the envelope.
+            let envelope = expected.to_json_envelope()?;
+            assert_eq!(expected, Transaction::from_json_envelope(&envelope)?);
+
+            // Ensure the envelope fields are present.
+            let value: serde_json::Value = serde_json::from_str(&envelope)?;
+            assert_eq!(value["version"], ENVELOPE_VERSION);
+            assert_eq!(value["network"], CurrentNetwork::ID);
+            assert_eq!(value["transaction"], serde_json::to_value(&expected)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_json_envelope_mismatch() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let transaction = crate::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng);
+        let envelope: serde_json::Value = serde_json::from_str(&transaction.to_json_envelope()?)?;
+
+        // Ensure a mismatched network fails.
+        let mut candidate = envelope.clone();
+        candidate["network"] = (CurrentNetwork::ID + 1).into();
+        assert!(Transaction::<CurrentNetwork>::from_json_envelope(&candidate.to_string()).is_err());
+
+        // Ensure a mismatched version fails.
+        let mut candidate = envelope.clone();
+        candidate["version"] = (ENVELOPE_VERSION + 1).into();
+        assert!(Transaction::<CurrentNetwork>::from_json_envelope(&candidate.to_string()).is_err());
+
+        // Ensure a missing network fails.
+        let mut candidate = envelope;
+        candidate.as_object_mut().unwrap().remove("network");
+        assert!(Transaction::<CurrentNetwork>::from_json_envelope(&candidate.to_string()).is_err());
+        Ok(())
+    }
+}
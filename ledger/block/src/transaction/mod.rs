@@ -21,7 +21,12 @@ pub use execution::*;
 mod fee;
 pub use fee::*;
 
+mod summary;
+pub use summary::*;
+
 mod bytes;
+mod diff;
+mod envelope;
 mod merkle;
 mod serialize;
 mod string;
@@ -99,6 +104,13 @@ impl<N: Network> Transaction<N> {
     }
 }
 
+impl<N: Network> Transaction<N> {
+    /// Returns the size in bytes.
+    pub fn size_in_bytes(&self) -> Result<u64> {
+        Ok(u64::try_from(self.to_bytes_le()?.len())?)
+    }
+}
+
 impl<N: Network> Transaction<N> {
     /// Returns `true` if this transaction contains a call to `credits.aleo/split`.
     #[inline]
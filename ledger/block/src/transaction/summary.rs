@@ -0,0 +1,146 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A summary of a transaction, which omits the transition inputs and outputs (e.g. record ciphertexts).
+/// This is intended for logging transactions without exposing their contents.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransactionSummary<N: Network> {
+    /// The transaction ID.
+    id: N::TransactionID,
+    /// The network ID.
+    network_id: u16,
+    /// The transaction type.
+    variant: &'static str,
+    /// The number of transitions.
+    num_transitions: usize,
+    /// The number of transition inputs.
+    num_inputs: usize,
+    /// The number of transition outputs.
+    num_outputs: usize,
+    /// The fee amount, in microcredits.
+    fee_amount: u64,
+}
+
+impl<N: Network> TransactionSummary<N> {
+    /// Returns the transaction ID.
+    pub const fn id(&self) -> N::TransactionID {
+        self.id
+    }
+
+    /// Returns the network ID.
+    pub const fn network_id(&self) -> u16 {
+        self.network_id
+    }
+
+    /// Returns the transaction type.
+    pub const fn variant(&self) -> &'static str {
+        self.variant
+    }
+
+    /// Returns the number of transitions.
+    pub const fn num_transitions(&self) -> usize {
+        self.num_transitions
+    }
+
+    /// Returns the number of transition inputs.
+    pub const fn num_inputs(&self) -> usize {
+        self.num_inputs
+    }
+
+    /// Returns the number of transition outputs.
+    pub const fn num_outputs(&self) -> usize {
+        self.num_outputs
+    }
+
+    /// Returns the fee amount, in microcredits.
+    pub const fn fee_amount(&self) -> u64 {
+        self.fee_amount
+    }
+}
+
+impl<N: Network> Display for TransactionSummary<N> {
+    /// Prints the transaction summary as a single line.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} (type: {}, network: {}, transitions: {}, inputs: {}, outputs: {}, fee: {} microcredits)",
+            self.id,
+            self.variant,
+            self.network_id,
+            self.num_transitions,
+            self.num_inputs,
+            self.num_outputs,
+            self.fee_amount
+        )
+    }
+}
+
+impl<N: Network> Transaction<N> {
+    /// Returns a summary of the transaction, which omits the transition inputs and outputs.
+    pub fn to_redacted_summary(&self) -> Result<TransactionSummary<N>> {
+        // Determine the transaction type.
+        let variant = match self {
+            Self::Deploy(..) => "deploy",
+            Self::Execute(..) => "execute",
+            Self::Fee(..) => "fee",
+        };
+        Ok(TransactionSummary {
+            id: self.id(),
+            network_id: N::ID,
+            variant,
+            num_transitions: self.transitions().count(),
+            num_inputs: self.transitions().map(|transition| transition.inputs().len()).sum(),
+            num_outputs: self.transitions().map(|transition| transition.outputs().len()).sum(),
+            fee_amount: *self.fee_amount()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_redacted_summary() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for transaction in [
+            crate::transaction::test_helpers::sample_deployment_transaction(true, rng),
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng),
+            crate::transaction::test_helpers::sample_private_fee_transaction(rng),
+        ]
+        .into_iter()
+        {
+            let summary = transaction.to_redacted_summary()?;
+            let string = summary.to_string();
+
+            // Ensure the summary includes the transaction ID.
+            assert_eq!(summary.id(), transaction.id());
+            assert!(string.contains(&transaction.id().to_string()));
+            // Ensure the summary counts the transition inputs and outputs.
+            assert_eq!(summary.num_transitions(), transaction.transitions().count());
+            assert_eq!(summary.num_outputs(), transaction.transitions().map(|t| t.outputs().len()).sum::<usize>());
+            assert_eq!(summary.fee_amount(), *transaction.fee_amount()?);
+
+            // Ensure the summary omits the record ciphertexts.
+            for (_, record) in transaction.records() {
+                assert!(!string.contains(&record.to_string()));
+                assert!(!format!("{summary:?}").contains(&record.to_string()));
+            }
+        }
+        Ok(())
+    }
+}
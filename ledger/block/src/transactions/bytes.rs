@@ -53,6 +53,9 @@ impl<N: Network> ToBytes for Transactions<N> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
 
     #[test]
     fn test_bytes() -> Result<()> {
@@ -65,4 +68,14 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_bytes_too_many_transactions() -> Result<()> {
+        // Construct the bytes of a version 1 transactions list that claims one more transaction than allowed.
+        let num_txs = u32::try_from(Transactions::<CurrentNetwork>::MAX_TRANSACTIONS + 1)?;
+        let bytes = [[1u8].as_slice(), &num_txs.to_le_bytes()].concat();
+        // Ensure the over-count transactions are rejected before reading any transaction.
+        assert!(Transactions::<CurrentNetwork>::read_le(&bytes[..]).is_err());
+        Ok(())
+    }
 }
@@ -76,4 +76,25 @@ mod tests {
         // Ensure the log2 relationship between depth and the maximum number of transactions.
         assert_eq!(2usize.pow(TRANSACTIONS_DEPTH as u32), Transactions::<CurrentNetwork>::MAX_TRANSACTIONS);
     }
+
+    #[test]
+    fn test_transactions_tree_paths() -> Result<()> {
+        // Load the genesis block.
+        let block = crate::Block::<CurrentNetwork>::read_le(CurrentNetwork::genesis_bytes())?;
+        let transactions = block.transactions();
+
+        // Compute the transactions tree and root.
+        let tree = transactions.to_tree()?;
+        let root = transactions.to_transactions_root()?;
+        assert_eq!(*tree.root(), root);
+
+        for (index, transaction_id) in transactions.transaction_ids().enumerate() {
+            // Ensure a path generated from the tree verifies against the transactions root.
+            let path = tree.prove(index, &transaction_id.to_bits_le())?;
+            assert!(CurrentNetwork::verify_merkle_path_bhp(&path, &root, &transaction_id.to_bits_le()));
+            // Ensure the path matches the one from `to_path`.
+            assert_eq!(path, transactions.to_path(*transaction_id)?);
+        }
+        Ok(())
+    }
 }
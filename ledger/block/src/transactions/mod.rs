@@ -25,9 +25,11 @@ mod string;
 
 use crate::{Transaction, Transition};
 use console::{
+    account::ViewKey,
     network::prelude::*,
     program::{
         Ciphertext,
+        Plaintext,
         ProgramOwner,
         Record,
         TransactionsPath,
@@ -168,6 +170,46 @@ impl<N: Network> Transactions<N> {
     }
 }
 
+impl<N: Network> Transactions<N> {
+    /// Returns the decrypted records that belong to the given view key, grouped by transaction index in ascending order.
+    /// Note: Transactions without any records belonging to the view key are omitted.
+    /// Returns an error if a record belongs to the view key, but fails to decrypt.
+    #[allow(clippy::type_complexity)]
+    pub fn scan(&self, view_key: &ViewKey<N>) -> Result<Vec<(usize, Vec<(Field<N>, Record<N, Plaintext<N>>)>)>> {
+        // Derive the address corresponding to the given view key.
+        let address = view_key.to_address();
+
+        // Decrypt the records that belong to the view key, for each transaction.
+        cfg_iter!(self)
+            .enumerate()
+            .filter_map(|(index, transaction)| {
+                let records = transaction
+                    .records()
+                    .filter_map(|(commitment, record)| {
+                        // Compute the record view key.
+                        let record_view_key = (*record.nonce() * **view_key).to_x_coordinate();
+                        // Ensure the record belongs to the view key, before decrypting the record.
+                        if !record.is_owner_with_record_view_key(&address, &record_view_key) {
+                            return None;
+                        }
+                        Some(
+                            record
+                                .decrypt_symmetric_unchecked(&record_view_key)
+                                .map(|record| (*commitment, record))
+                                .map_err(|e| anyhow!("Failed to decrypt the record '{commitment}': {e}")),
+                        )
+                    })
+                    .collect::<Result<Vec<_>>>();
+                // Omit the transaction if it contains no records belonging to the view key.
+                match records {
+                    Ok(records) if records.is_empty() => None,
+                    records => Some(records.map(|records| (index, records))),
+                }
+            })
+            .collect()
+    }
+}
+
 impl<N: Network> Transactions<N> {
     /// The maximum number of aborted transactions allowed in a block.
     pub const MAX_ABORTED_TRANSACTIONS: usize = Subdag::<N>::MAX_ROUNDS
@@ -350,9 +392,93 @@ pub mod test_helpers {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{Execution, Output};
+    use console::{account::PrivateKey, program::Entry};
 
     type CurrentNetwork = console::network::Testnet3;
 
+    #[test]
+    fn test_scan() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis block, and the genesis transaction, which pays the genesis account.
+        let (block, transaction, private_key) = crate::test_helpers::sample_genesis_block_and_components(rng);
+        let view_key = ViewKey::try_from(&private_key).unwrap();
+        // Sample a deployment with a public fee, which contains no records.
+        let other_transaction = crate::transactions::confirmed::test_helpers::sample_accepted_deploy(0, false, rng);
+
+        // Prepare the transactions, with the genesis transaction second.
+        let transactions = Transactions::<CurrentNetwork>::from_iter([
+            other_transaction,
+            block.transactions().iter().next().unwrap().clone(),
+        ]);
+
+        // Ensure only the genesis transaction is returned, with all of its records.
+        let expected = transaction
+            .records()
+            .map(|(commitment, record)| (*commitment, record.decrypt(&view_key).unwrap()))
+            .collect::<Vec<_>>();
+        assert!(!expected.is_empty());
+        assert_eq!(transactions.scan(&view_key).unwrap(), vec![(1, expected)]);
+
+        // Ensure nothing is returned for an unrelated view key.
+        let other_view_key = ViewKey::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        assert!(transactions.scan(&other_view_key).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_scan_reports_undecryptable_records() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis transaction, which pays the genesis account.
+        let (_, transaction, private_key) = crate::test_helpers::sample_genesis_block_and_components(rng);
+        let view_key = ViewKey::try_from(&private_key).unwrap();
+
+        // Replace the data of each record with an empty ciphertext, which cannot be decrypted.
+        let execution = transaction.execution().unwrap();
+        let transitions = execution.transitions().map(|transition| {
+            let outputs = transition
+                .outputs()
+                .iter()
+                .map(|output| match output {
+                    Output::Record(commitment, checksum, Some(record)) => {
+                        let data = record
+                            .data()
+                            .keys()
+                            .map(|name| (*name, Entry::Private(Ciphertext::try_from(vec![]).unwrap())))
+                            .collect();
+                        let record =
+                            Record::<CurrentNetwork, Ciphertext<CurrentNetwork>>::from_ciphertext(record.owner().clone(), data, *record.nonce()).unwrap();
+                        Output::Record(*commitment, *checksum, Some(record))
+                    }
+                    output => output.clone(),
+                })
+                .collect();
+            Transition::new(
+                *transition.program_id(),
+                *transition.function_name(),
+                transition.inputs().to_vec(),
+                outputs,
+                *transition.tpk(),
+                *transition.tcm(),
+            )
+            .unwrap()
+        });
+        let execution =
+            Execution::from(transitions, execution.global_state_root(), execution.proof().cloned()).unwrap();
+        let transaction = Transaction::from_execution(execution, transaction.fee_transition()).unwrap();
+        let transactions = Transactions::<CurrentNetwork>::from_iter([
+            ConfirmedTransaction::accepted_execute(0, transaction, vec![]).unwrap(),
+        ]);
+
+        // Ensure the records that belong to the view key, but fail to decrypt, are reported.
+        assert!(transactions.scan(&view_key).is_err());
+
+        // Ensure nothing is returned for an unrelated view key.
+        let other_view_key = ViewKey::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        assert!(transactions.scan(&other_view_key).unwrap().is_empty());
+    }
+
     #[test]
     fn test_max_transactions() {
         assert_eq!(
@@ -97,6 +97,24 @@ impl<N: Network> Block<N> {
         // Return the expected existing transaction ids.
         Ok(expected_existing_transaction_ids)
     }
+
+    /// Ensures the block is internally consistent, without its previous block or the ledger state.
+    /// Note: This check does not verify the block authority, solutions, ratifications, or finalize root.
+    pub fn verify_integrity(&self) -> Result<()> {
+        // Ensure the block header is well-formed.
+        ensure!(self.header.is_valid(), "Block {} has an invalid block header", self.height());
+        // Ensure the block hash is correct.
+        self.verify_hash(self.height().saturating_sub(1), self.previous_hash)?;
+        // Ensure the block transactions are correct.
+        self.verify_transactions()?;
+        // Ensure the transactions root matches the block header.
+        ensure!(
+            self.header.transactions_root() == self.compute_transactions_root()?,
+            "Block {} has an incorrect transactions root",
+            self.height()
+        );
+        Ok(())
+    }
 }
 
 impl<N: Network> Block<N> {
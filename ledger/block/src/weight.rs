@@ -0,0 +1,82 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Block<N> {
+    /// Returns the weight of the block, where each proof byte counts `N::PROOF_WEIGHT_MULTIPLIER` times,
+    /// and every other byte counts once.
+    /// Note: The block and its proofs are both measured by their serialized length.
+    pub fn weight(&self) -> Result<u64> {
+        // Determine the number of bytes in the block.
+        let size_in_bytes = self.size_in_bytes()?;
+        // Determine the number of proof bytes in the block.
+        let mut proof_size_in_bytes = 0u64;
+        for transaction in self.transactions.iter() {
+            let (execution_proof, fee_proof) = match transaction.transaction() {
+                Transaction::Deploy(_, _, _, fee) => (None, fee.proof()),
+                Transaction::Execute(_, execution, fee) => {
+                    (execution.proof(), fee.as_ref().and_then(|fee| fee.proof()))
+                }
+                Transaction::Fee(_, fee) => (None, fee.proof()),
+            };
+            for proof in execution_proof.into_iter().chain(fee_proof) {
+                proof_size_in_bytes = proof_size_in_bytes
+                    .checked_add(u64::try_from(proof.to_bytes_le()?.len())?)
+                    .ok_or_else(|| anyhow!("The proof size computation overflowed for a block"))?;
+            }
+        }
+        // Compute the additional weight of the proof bytes, as they are already counted once in the block size.
+        let proof_weight = proof_size_in_bytes
+            .checked_mul(N::PROOF_WEIGHT_MULTIPLIER.saturating_sub(1))
+            .ok_or_else(|| anyhow!("The proof weight computation overflowed for a block"))?;
+        // Return the weight of the block.
+        size_in_bytes.checked_add(proof_weight).ok_or_else(|| anyhow!("The weight computation overflowed for a block"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_weight() -> Result<()> {
+        // Load the genesis block.
+        let block = Block::<CurrentNetwork>::read_le(CurrentNetwork::genesis_bytes())?;
+        // Ensure the genesis block contains proofs.
+        assert!(block.executions().any(|transaction| transaction.execution().and_then(|e| e.proof()).is_some()));
+
+        // Ensure the weight of a block with proofs exceeds its size in bytes.
+        assert!(block.weight()? > block.size_in_bytes()?);
+
+        // Compute the number of proof bytes in the block.
+        let mut proof_size_in_bytes = 0;
+        for transaction in block.transactions().iter() {
+            if let Some(proof) = transaction.transaction().execution().and_then(|execution| execution.proof()) {
+                proof_size_in_bytes += u64::try_from(proof.to_bytes_le()?.len())?;
+            }
+            if let Some(proof) = transaction.transaction().fee_transition().and_then(|fee| fee.proof().cloned()) {
+                proof_size_in_bytes += u64::try_from(proof.to_bytes_le()?.len())?;
+            }
+        }
+        // Ensure the weight counts each proof byte `PROOF_WEIGHT_MULTIPLIER` times in total.
+        let expected_weight =
+            block.size_in_bytes()? + (CurrentNetwork::PROOF_WEIGHT_MULTIPLIER - 1) * proof_size_in_bytes;
+        assert_eq!(block.weight()?, expected_weight);
+        Ok(())
+    }
+}
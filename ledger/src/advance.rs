@@ -103,6 +103,9 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             self.current_epoch_challenge.write().clone_from(&self.get_epoch_challenge(block.height()).ok());
         }
 
+        // Notify the subscribers of the new block.
+        self.notify_subscribers(block);
+
         Ok(())
     }
 }
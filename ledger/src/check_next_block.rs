@@ -31,6 +31,11 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             bail!("Block height '{height}' already exists in the ledger")
         }
 
+        // Ensure the block timestamp is after the median time past, from the activation height onwards.
+        if height >= N::MEDIAN_TIME_PAST_HEIGHT {
+            self.check_median_time_past(block)?;
+        }
+
         // Ensure the solutions do not already exist.
         if let Some(solutions) = block.solutions() {
             for puzzle_commitment in solutions.puzzle_commitments() {
@@ -41,12 +46,25 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         }
 
         // Ensure each transaction is well-formed and unique.
+        // Note: The transactions are checked in parallel, and the first invalid transaction (in block order) is reported.
+        // Transactions after an invalid transaction are not required to be checked.
         let transactions = block.transactions();
         let rngs = (0..transactions.len()).map(|_| StdRng::from_seed(rng.gen())).collect::<Vec<_>>();
-        cfg_iter!(transactions).zip(rngs).try_for_each(|(transaction, mut rng)| {
-            self.check_transaction_basic(transaction, transaction.to_rejected_id()?, &mut rng)
-                .map_err(|e| anyhow!("Invalid transaction found in the transactions list: {e}"))
-        })?;
+        let check_transaction = |(transaction, mut rng): (&ConfirmedTransaction<N>, StdRng)| {
+            let result = transaction
+                .to_rejected_id()
+                .and_then(|rejected_id| self.check_transaction_basic(transaction, rejected_id, &mut rng));
+            result
+                .err()
+                .map(|e| anyhow!("Invalid transaction '{}' found in the transactions list: {e}", transaction.id()))
+        };
+        #[cfg(not(feature = "serial"))]
+        let first_error = transactions.par_iter().zip(rngs).find_map_first(check_transaction);
+        #[cfg(feature = "serial")]
+        let first_error = transactions.iter().zip(rngs).find_map(check_transaction);
+        if let Some(error) = first_error {
+            return Err(error);
+        }
 
         // TODO (howardwu): Remove this after moving the total supply into credits.aleo.
         {
@@ -104,4 +122,13 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
 
         Ok(())
     }
+
+    /// Checks the timestamp of the given next block is after the median time past of the latest block.
+    pub fn check_median_time_past(&self, block: &Block<N>) -> Result<()> {
+        let median_time_past = self.get_median_time_past(self.latest_height())?;
+        if block.timestamp() <= median_time_past {
+            bail!("Block {} has a timestamp at or before the median time past ({median_time_past})", block.height())
+        }
+        Ok(())
+    }
 }
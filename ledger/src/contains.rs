@@ -30,6 +30,15 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         self.vm.block_store().contains_block_hash(block_hash)
     }
 
+    /// Returns `true` if the given block hash is on the canonical chain.
+    pub fn is_canonical(&self, block_hash: &N::BlockHash) -> bool {
+        // Ensure the block hash is the one stored at its block height.
+        match self.vm.block_store().get_block_height(block_hash) {
+            Ok(Some(height)) => matches!(self.get_hash(height), Ok(hash) if hash == *block_hash),
+            _ => false,
+        }
+    }
+
     /// Returns `true` if the given batch certificate ID exists.
     pub fn contains_certificate(&self, certificate_id: &Field<N>) -> Result<bool> {
         self.vm.block_store().contains_certificate(certificate_id)
@@ -15,6 +15,9 @@
 use super::*;
 
 impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// The maximum number of state paths to cache.
+    pub(crate) const MAX_CACHED_STATE_PATHS: usize = 1 << 10;
+
     /// Returns the committee for the given `block height`.
     pub fn get_committee(&self, block_height: u32) -> Result<Option<Committee<N>>> {
         self.vm.finalize_store().committee_store().get_committee(block_height)
@@ -31,8 +34,35 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
     }
 
     /// Returns a state path for the given commitment.
+    /// Note: State paths are cached until the state root of the ledger changes,
+    /// and the least recently used state path is evicted when the cache is full.
     pub fn get_state_path_for_commitment(&self, commitment: &Field<N>) -> Result<StatePath<N>> {
-        self.vm.block_store().get_state_path_for_commitment(commitment)
+        // If the state path is cached for the latest state root, return it.
+        {
+            let mut state_paths = self.state_paths.write();
+            if let Some(state_path) = state_paths.shift_remove(commitment) {
+                if state_path.global_state_root() == self.latest_state_root() {
+                    // Reinsert the state path at the back of the cache, as it is the most recently used.
+                    state_paths.insert(*commitment, state_path.clone());
+                    return Ok(state_path);
+                }
+            }
+        }
+
+        // Compute the state path.
+        let state_path = self.vm.block_store().get_state_path_for_commitment(commitment)?;
+
+        // Update the cache.
+        let mut state_paths = self.state_paths.write();
+        // Evict the state paths for any other state root, as they are stale.
+        state_paths.retain(|_, cached| cached.global_state_root() == state_path.global_state_root());
+        // Evict the least recently used state path, if the cache is full.
+        if state_paths.len() >= Self::MAX_CACHED_STATE_PATHS {
+            state_paths.shift_remove_index(0);
+        }
+        state_paths.insert(*commitment, state_path.clone());
+
+        Ok(state_path)
     }
 
     /// Returns the epoch challenge for the given block height.
@@ -130,6 +160,34 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         }
     }
 
+    /// Returns the median timestamp of the latest `MEDIAN_TIME_PAST_WINDOW` blocks, up to the given block height.
+    /// Note: This reads at most `MEDIAN_TIME_PAST_WINDOW` block headers from storage.
+    pub fn get_median_time_past(&self, height: u32) -> Result<i64> {
+        // Determine the starting block height of the window.
+        let start_height = height.saturating_sub(MEDIAN_TIME_PAST_WINDOW - 1);
+        // Retrieve the block timestamps in the window.
+        let timestamps = (start_height..=height)
+            .map(|height| Ok(self.get_header(height)?.timestamp()))
+            .collect::<Result<Vec<_>>>()?;
+        // Compute the median timestamp.
+        median_time_past(&timestamps).ok_or_else(|| anyhow!("Missing block timestamps for block {height}"))
+    }
+
+    /// Returns the mean interval in seconds between the latest `last_n` blocks and their previous blocks,
+    /// or `None` if the ledger has no such blocks.
+    pub fn average_block_time(&self, last_n: usize) -> Option<f64> {
+        // Determine the starting block height.
+        let latest_height = self.latest_height();
+        let start_height = latest_height.saturating_sub(u32::try_from(last_n).unwrap_or(u32::MAX));
+        // Retrieve the block timestamps.
+        let timestamps = (start_height..=latest_height)
+            .map(|height| Ok(self.get_header(height)?.timestamp()))
+            .collect::<Result<Vec<_>>>()
+            .ok()?;
+        // Compute the average block time.
+        average_block_time(&timestamps)
+    }
+
     /// Returns the block transactions for the given block height.
     pub fn get_transactions(&self, height: u32) -> Result<Transactions<N>> {
         // If the height is 0, return the genesis block transactions.
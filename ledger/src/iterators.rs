@@ -15,6 +15,12 @@
 use super::*;
 
 impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Returns an iterator over the blocks, from the given `start height` up to the latest block height.
+    /// Each block is fetched from storage on demand, and a failure to fetch one block is yielded as an error.
+    pub fn blocks_from(&self, start_height: u32) -> impl '_ + Iterator<Item = Result<Block<N>>> {
+        (start_height..=self.latest_height()).map(move |height| self.get_block(height))
+    }
+
     /// Returns an iterator over the state roots, for all blocks in `self`.
     pub fn state_roots(&self) -> impl '_ + Iterator<Item = Cow<'_, N::StateRoot>> {
         self.vm.block_store().state_roots()
@@ -41,6 +41,10 @@ mod contains;
 mod find;
 mod get;
 mod iterators;
+mod orphan;
+
+mod subscribe;
+pub use subscribe::*;
 
 #[cfg(test)]
 mod tests;
@@ -107,6 +111,12 @@ pub struct Ledger<N: Network, C: ConsensusStorage<N>> {
     current_committee: Arc<RwLock<Option<Committee<N>>>>,
     /// The current block.
     current_block: Arc<RwLock<Block<N>>>,
+    /// The senders for the block event subscribers.
+    subscribers: Arc<RwLock<Vec<std::sync::mpsc::Sender<BlockEvent<N>>>>>,
+    /// The cache of recent state paths for commitments.
+    state_paths: Arc<RwLock<IndexMap<Field<N>, StatePath<N>>>>,
+    /// The orphan blocks, keyed by their previous block hash.
+    orphans: Arc<RwLock<IndexMap<N::BlockHash, Block<N>>>>,
 }
 
 impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
@@ -169,6 +179,9 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             current_epoch_challenge: Default::default(),
             current_committee: Arc::new(RwLock::new(current_committee)),
             current_block: Arc::new(RwLock::new(genesis_block.clone())),
+            subscribers: Default::default(),
+            state_paths: Default::default(),
+            orphans: Default::default(),
         };
 
         // If the block store is empty, initialize the genesis block.
@@ -293,6 +306,21 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         self.current_block.read().last_coinbase_timestamp()
     }
 
+    /// Returns the coinbase target for the next block, if it were to be produced at the given timestamp.
+    pub fn next_coinbase_target(&self, next_timestamp: i64) -> Result<u64> {
+        // Retrieve the latest block.
+        let block = self.current_block.read();
+        // Compute the next coinbase target.
+        coinbase_target(
+            block.last_coinbase_target(),
+            block.last_coinbase_timestamp(),
+            next_timestamp,
+            N::ANCHOR_TIME,
+            N::NUM_BLOCKS_PER_EPOCH,
+            N::GENESIS_COINBASE_TARGET,
+        )
+    }
+
     /// Returns the latest block timestamp.
     pub fn latest_timestamp(&self) -> i64 {
         self.current_block.read().timestamp()
@@ -381,6 +409,50 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             rng,
         )
     }
+
+    /// Creates a transfer transaction for each unspent `credits.aleo` record of the given account,
+    /// moving the full balance of each record to the given address.
+    ///
+    /// The fees are paid from the public balance of the account, and
+    /// the `priority_fee_in_microcredits` is an additional fee **on top** of each execution fee.
+    pub fn create_migration<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        to: Address<N>,
+        priority_fee_in_microcredits: u64,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<Vec<Transaction<N>>> {
+        // Fetch the unspent records.
+        let records = self.find_unspent_credits_records(&ViewKey::try_from(private_key)?)?;
+        ensure!(!records.len().is_zero(), "The Aleo account has no records to spend.");
+
+        let microcredits = Identifier::from_str("microcredits")?;
+        let mut transactions = Vec::with_capacity(records.len());
+        for record in records.into_values() {
+            // Retrieve the balance of the record.
+            let amount = match record.find(&[microcredits])? {
+                Entry::Private(Plaintext::Literal(Literal::U64(amount), _)) => *amount,
+                _ => bail!("The record does not contain a private 'microcredits' entry"),
+            };
+
+            // Prepare the inputs.
+            let inputs =
+                [Value::Record(record), Value::from_str(&format!("{to}"))?, Value::from_str(&format!("{amount}u64"))?];
+
+            // Create a new execute transaction.
+            transactions.push(self.vm.execute(
+                private_key,
+                ("credits.aleo", "transfer_private"),
+                inputs.iter(),
+                None,
+                priority_fee_in_microcredits,
+                query.clone(),
+                rng,
+            )?);
+        }
+        Ok(transactions)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,76 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// The maximum number of orphan blocks held by the ledger.
+    pub const MAX_ORPHAN_BLOCKS: usize = 1 << 6;
+
+    /// Holds the given block until its previous block is added to the ledger.
+    /// Note: An orphan block is rejected if an orphan block with the same previous block is already held.
+    pub fn add_orphan(&self, block: Block<N>) -> Result<()> {
+        // Ensure the previous block is not already in the ledger.
+        if self.contains_block_hash(&block.previous_hash())? {
+            bail!("Block '{}' is not an orphan, as its previous block is in the ledger", block.hash())
+        }
+        // Ensure the block is above the latest block.
+        if block.height() <= self.latest_height() {
+            bail!("Orphan block {} is not above the latest block height ({})", block.height(), self.latest_height())
+        }
+
+        // Ensure the block is internally consistent.
+        block.verify_integrity()?;
+
+        // Acquire the write lock on the orphan blocks.
+        let mut orphans = self.orphans.write();
+        // Ensure an orphan block with the same previous block is not already held.
+        if orphans.contains_key(&block.previous_hash()) {
+            bail!("An orphan block with previous block '{}' is already held", block.previous_hash())
+        }
+        // Ensure the orphan pool is not full.
+        if orphans.len() >= Self::MAX_ORPHAN_BLOCKS {
+            bail!("Cannot hold more than {} orphan blocks", Self::MAX_ORPHAN_BLOCKS)
+        }
+        // Insert the orphan block, keyed by its previous block hash.
+        orphans.insert(block.previous_hash(), block);
+        Ok(())
+    }
+
+    /// Returns the number of orphan blocks held by the ledger.
+    pub fn num_orphans(&self) -> usize {
+        self.orphans.read().len()
+    }
+
+    /// Checks and adds the held orphan blocks that extend the latest block, until none remain,
+    /// and returns the heights of the added blocks in ascending order.
+    /// Note: If an orphan block fails the check, it is dropped and the error is returned,
+    /// while the orphan blocks added before it remain in the ledger.
+    pub fn connect_orphans<R: CryptoRng + Rng>(&self, rng: &mut R) -> Result<Vec<u32>> {
+        let mut heights = Vec::new();
+        loop {
+            // Retrieve the orphan block that extends the latest block, if one exists.
+            let block = match self.orphans.write().shift_remove(&self.latest_hash()) {
+                Some(block) => block,
+                None => break,
+            };
+            // Check and add the orphan block.
+            self.check_next_block(&block, rng)
+                .and_then(|_| self.advance_to_next_block(&block))
+                .map_err(|error| anyhow!("Failed to connect orphan block {}: {error}", block.height()))?;
+            heights.push(block.height());
+        }
+        Ok(heights)
+    }
+}
@@ -0,0 +1,56 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use std::sync::mpsc;
+
+/// An event emitted to subscribers each time a block is added to the ledger.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct BlockEvent<N: Network> {
+    /// The height of the added block.
+    height: u32,
+    /// The hash of the added block.
+    hash: N::BlockHash,
+}
+
+impl<N: Network> BlockEvent<N> {
+    /// Returns the height of the added block.
+    pub const fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns the hash of the added block.
+    pub const fn hash(&self) -> N::BlockHash {
+        self.hash
+    }
+}
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Returns a receiver that is sent a `BlockEvent` each time a block is added to the ledger.
+    /// Note: Orphan blocks connected by `Ledger::connect_orphans` are sent in block order,
+    /// before `Ledger::connect_orphans` returns.
+    pub fn subscribe(&self) -> mpsc::Receiver<BlockEvent<N>> {
+        let (sender, receiver) = mpsc::channel();
+        self.subscribers.write().push(sender);
+        receiver
+    }
+
+    /// Notifies the subscribers that the given block was added to the ledger.
+    pub(crate) fn notify_subscribers(&self, block: &Block<N>) {
+        let event = BlockEvent { height: block.height(), hash: block.hash() };
+        // Send the event, and drop any subscribers whose receiver has been dropped.
+        self.subscribers.write().retain(|sender| sender.send(event).is_ok());
+    }
+}
@@ -19,11 +19,12 @@ use crate::{
 };
 use aleo_std::StorageMode;
 use console::{
-    account::{Address, PrivateKey},
+    account::{Address, PrivateKey, ViewKey},
     network::prelude::*,
     program::{Entry, Identifier, Literal, Plaintext, ProgramID, Value},
+    types::Field,
 };
-use ledger_block::{ConfirmedTransaction, Rejected, Transaction};
+use ledger_block::{Block, ConfirmedTransaction, Header, Metadata, Rejected, Transaction, Transactions};
 use ledger_store::{helpers::memory::ConsensusMemory, ConsensusStore};
 use synthesizer::{program::Program, vm::VM};
 
@@ -79,7 +80,313 @@ fn test_state_path() {
     let commitments = block.transactions().commitments().collect::<Vec<_>>();
     let commitment = commitments[0];
 
-    let _state_path = ledger.get_state_path_for_commitment(commitment).unwrap();
+    let state_path = ledger.get_state_path_for_commitment(commitment).unwrap();
+    // Ensure the state path is bound to the latest state root of the ledger.
+    assert_eq!(state_path.global_state_root(), ledger.latest_state_root());
+    // Ensure the state path is valid, and proves the given commitment.
+    assert!(state_path.verify(true, Field::zero()).is_ok());
+    assert_eq!(state_path.transition_leaf().id(), *commitment);
+
+    // Ensure a state path cannot be constructed for a commitment that is not in the ledger.
+    assert!(ledger.get_state_path_for_commitment(&Field::rand(rng)).is_err());
+}
+
+#[test]
+fn test_state_path_cache() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the ledger.
+    let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let ledger = crate::test_helpers::sample_ledger(private_key, rng);
+    // Retrieve a commitment from the genesis block.
+    let commitment = *ledger.get_block(0).unwrap().transactions().commitments().next().unwrap();
+
+    // Ensure repeated state paths for the same commitment are equal.
+    let state_path = ledger.get_state_path_for_commitment(&commitment).unwrap();
+    assert_eq!(state_path, ledger.get_state_path_for_commitment(&commitment).unwrap());
+
+    // Advance to the next block.
+    let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Ensure the cached state path is not returned for the new state root.
+    let next_state_path = ledger.get_state_path_for_commitment(&commitment).unwrap();
+    assert_ne!(state_path.global_state_root(), next_state_path.global_state_root());
+    assert_eq!(next_state_path.global_state_root(), ledger.latest_state_root());
+    assert_eq!(next_state_path, ledger.vm().block_store().get_state_path_for_commitment(&commitment).unwrap());
+    assert!(next_state_path.verify(true, Field::zero()).is_ok());
+}
+
+#[test]
+fn test_state_path_cache_evicts_least_recently_used() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the ledger.
+    let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let ledger = crate::test_helpers::sample_ledger(private_key, rng);
+    // Retrieve two commitments from the genesis block.
+    let mut commitments = ledger.get_block(0).unwrap().transactions().commitments().copied().collect::<Vec<_>>();
+    let (hot_commitment, cold_commitment) = (commitments.remove(0), commitments.remove(0));
+
+    // Cache the state path for the hot commitment, as the oldest entry in the cache.
+    let state_path = ledger.get_state_path_for_commitment(&hot_commitment).unwrap();
+    // Fill the cache with state paths for the current state root.
+    {
+        let mut state_paths = ledger.state_paths.write();
+        while state_paths.len() < CurrentLedger::MAX_CACHED_STATE_PATHS {
+            state_paths.insert(Field::rand(rng), state_path.clone());
+        }
+    }
+    let oldest_commitment = *ledger.state_paths.read().get_index(1).unwrap().0;
+
+    // Use the hot commitment again.
+    assert_eq!(state_path, ledger.get_state_path_for_commitment(&hot_commitment).unwrap());
+    // Cache the state path for another commitment, which evicts an entry from the full cache.
+    ledger.get_state_path_for_commitment(&cold_commitment).unwrap();
+
+    // Ensure the least recently used entry is evicted, and the hot commitment survives.
+    let state_paths = ledger.state_paths.read();
+    assert_eq!(state_paths.len(), CurrentLedger::MAX_CACHED_STATE_PATHS);
+    assert!(state_paths.contains_key(&hot_commitment));
+    assert!(state_paths.contains_key(&cold_commitment));
+    assert!(!state_paths.contains_key(&oldest_commitment));
+}
+
+#[test]
+fn test_subscribe() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the ledger.
+    let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let ledger = crate::test_helpers::sample_ledger(private_key, rng);
+    // Subscribe to the ledger.
+    let receiver = ledger.subscribe();
+
+    // Construct the next block.
+    let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+    // Advance to the next block.
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Ensure exactly one event was delivered for the new block.
+    let event = receiver.try_recv().unwrap();
+    assert_eq!(event.height(), 1);
+    assert_eq!(event.hash(), block.hash());
+    assert!(receiver.try_recv().is_err());
+}
+
+#[test]
+fn test_blocks_from() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the ledger.
+    let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let ledger = crate::test_helpers::sample_ledger(private_key, rng);
+
+    // Advance the ledger to a height of 4.
+    for _ in 0..4 {
+        let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+        ledger.advance_to_next_block(&block).unwrap();
+    }
+    assert_eq!(ledger.latest_height(), 4);
+
+    // Ensure iterating from genesis yields every block in ascending order.
+    let blocks = ledger.blocks_from(0).collect::<Result<Vec<_>>>().unwrap();
+    assert_eq!(blocks.len(), 5);
+    for (height, block) in (0u32..).zip(blocks.iter()) {
+        assert_eq!(block.height(), height);
+        assert_eq!(*block, ledger.get_block(height).unwrap());
+    }
+
+    // Ensure iterating from a later height stops at the latest block.
+    let heights = ledger.blocks_from(3).map(|block| block.unwrap().height()).collect::<Vec<_>>();
+    assert_eq!(heights, vec![3, 4]);
+
+    // Ensure iterating from beyond the latest block yields nothing.
+    assert_eq!(ledger.blocks_from(5).count(), 0);
+}
+
+#[test]
+fn test_average_block_time() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the ledger.
+    let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let ledger = crate::test_helpers::sample_ledger(private_key, rng);
+    // Ensure the genesis block alone has no average block time.
+    assert_eq!(ledger.average_block_time(1), None);
+
+    // Advance the ledger to a height of 2.
+    for _ in 0..2 {
+        let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+        ledger.advance_to_next_block(&block).unwrap();
+    }
+    let timestamps = (0..=2).map(|height| ledger.get_header(height).unwrap().timestamp() as f64).collect::<Vec<_>>();
+
+    // Ensure the average block time is computed over the requested blocks.
+    assert_eq!(ledger.average_block_time(0), None);
+    assert_eq!(ledger.average_block_time(1), Some(timestamps[2] - timestamps[1]));
+    assert_eq!(ledger.average_block_time(2), Some((timestamps[2] - timestamps[0]) / 2.0));
+    // Ensure requesting more blocks than exist uses every block.
+    assert_eq!(ledger.average_block_time(10), ledger.average_block_time(2));
+}
+
+#[test]
+fn test_orphan_blocks() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the ledger.
+    let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let ledger = crate::test_helpers::sample_ledger(private_key, rng);
+    // Initialize a second ledger from the same genesis block.
+    let other_ledger = CurrentLedger::load(ledger.get_block(0).unwrap(), StorageMode::Production).unwrap();
+
+    // Construct blocks 1 and 2 on the first ledger.
+    let block_1 = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+    ledger.advance_to_next_block(&block_1).unwrap();
+    let block_2 = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+
+    // Ensure a block whose previous block is in the ledger is not held as an orphan.
+    assert!(other_ledger.add_orphan(block_1.clone()).is_err());
+
+    // Ensure a block with an incorrect block hash is not held as an orphan.
+    let invalid_block = Block::from_unchecked(
+        block_1.hash(),
+        block_2.previous_hash(),
+        *block_2.header(),
+        block_2.authority().clone(),
+        block_2.ratifications().clone(),
+        block_2.solutions().cloned(),
+        block_2.transactions().clone(),
+        block_2.aborted_transaction_ids().clone(),
+    )
+    .unwrap();
+    assert!(other_ledger.add_orphan(invalid_block).is_err());
+    assert_eq!(other_ledger.num_orphans(), 0);
+
+    // Submit block 2 before block 1.
+    other_ledger.add_orphan(block_2.clone()).unwrap();
+    assert_eq!(other_ledger.num_orphans(), 1);
+    assert_eq!(other_ledger.latest_height(), 0);
+
+    // Ensure a competing block with the same previous block does not replace the held orphan block.
+    let competing_block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+    assert_eq!(competing_block.previous_hash(), block_2.previous_hash());
+    assert!(other_ledger.add_orphan(competing_block).is_err());
+    assert_eq!(other_ledger.num_orphans(), 1);
+
+    // Subscribe to the second ledger.
+    let receiver = other_ledger.subscribe();
+
+    // Add block 1, and ensure block 2 is still held.
+    other_ledger.check_next_block(&block_1, rng).unwrap();
+    other_ledger.advance_to_next_block(&block_1).unwrap();
+    assert_eq!(other_ledger.num_orphans(), 1);
+    assert_eq!(other_ledger.latest_height(), 1);
+
+    // Connect the orphan blocks, and ensure block 2 is connected after block 1.
+    assert_eq!(other_ledger.connect_orphans(rng).unwrap(), vec![2]);
+    // Ensure an event was delivered for block 1, followed by the connected orphan block.
+    let events = receiver.try_iter().map(|event| (event.height(), event.hash())).collect::<Vec<_>>();
+    assert_eq!(events, vec![(1, block_1.hash()), (2, block_2.hash())]);
+    assert_eq!(other_ledger.num_orphans(), 0);
+    assert_eq!(other_ledger.latest_height(), 2);
+    assert_eq!(other_ledger.latest_hash(), block_2.hash());
+    assert_eq!(other_ledger.get_block(1).unwrap(), block_1);
+
+    // Ensure connecting again adds no blocks.
+    assert!(other_ledger.connect_orphans(rng).unwrap().is_empty());
+}
+
+#[test]
+fn test_is_canonical() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the ledger.
+    let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let ledger = crate::test_helpers::sample_ledger(private_key, rng);
+    // Ensure the genesis block is canonical.
+    assert!(ledger.is_canonical(&ledger.get_hash(0).unwrap()));
+
+    // Advance to the next block.
+    let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+    // Ensure the new tip is canonical.
+    assert!(ledger.is_canonical(&block.hash()));
+
+    // Ensure a block that was not added to the ledger is not canonical.
+    let candidate = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+    assert!(!ledger.is_canonical(&candidate.hash()));
+    // Ensure an unknown block hash is not canonical.
+    assert!(!ledger.is_canonical(&Default::default()));
+}
+
+#[test]
+fn test_create_migration() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, view_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Sample the new account.
+    let new_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let new_view_key = ViewKey::try_from(&new_private_key).unwrap();
+    let new_address = Address::try_from(&new_private_key).unwrap();
+
+    // Computes the total balance of the given records.
+    let balance = |records: &crate::RecordMap<CurrentNetwork>| -> u64 {
+        records
+            .values()
+            .map(|record| match record.find(&[Identifier::from_str("microcredits").unwrap()]).unwrap() {
+                Entry::Private(Plaintext::Literal(Literal::U64(amount), _)) => *amount,
+                _ => unreachable!("Expected a private 'microcredits' entry"),
+            })
+            .sum()
+    };
+
+    // Retrieve the records of the old account.
+    let records = ledger.find_unspent_credits_records(&view_key).unwrap();
+    assert!(!records.is_empty());
+
+    // Migrate the records to the new account.
+    let transactions = ledger.create_migration(&private_key, new_address, 0, None, rng).unwrap();
+    assert_eq!(transactions.len(), records.len());
+
+    // Construct the next block.
+    let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], transactions, rng).unwrap();
+    // Advance to the next block.
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Ensure the old account has no spendable records left.
+    assert!(ledger.find_unspent_credits_records(&view_key).unwrap().is_empty());
+    // Ensure the new account can decrypt the migrated records, with the same total balance.
+    let new_records = ledger.find_unspent_credits_records(&new_view_key).unwrap();
+    assert_eq!(new_records.len(), records.len());
+    assert_eq!(balance(&new_records), balance(&records));
+}
+
+#[test]
+fn test_next_coinbase_target() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the ledger.
+    let ledger = crate::test_helpers::sample_ledger(PrivateKey::<CurrentNetwork>::new(rng).unwrap(), rng);
+
+    for elapsed in [0, 1, CurrentNetwork::ANCHOR_TIME as i64, 3600, -10] {
+        let next_timestamp = ledger.latest_timestamp() + elapsed;
+        // Compute the expected coinbase target.
+        let expected = crate::block::coinbase_target(
+            ledger.last_coinbase_target(),
+            ledger.last_coinbase_timestamp(),
+            next_timestamp,
+            CurrentNetwork::ANCHOR_TIME,
+            CurrentNetwork::NUM_BLOCKS_PER_EPOCH,
+            CurrentNetwork::GENESIS_COINBASE_TARGET,
+        )
+        .unwrap();
+        // Ensure the next coinbase target matches.
+        assert_eq!(ledger.next_coinbase_target(next_timestamp).unwrap(), expected);
+    }
 }
 
 #[test]
@@ -1238,6 +1545,121 @@ finalize foo2:
     assert!(ledger.vm.block_store().contains_rejected_or_aborted_transaction_id(&deployment_2_id).unwrap());
 }
 
+#[test]
+fn test_check_next_block_reports_first_invalid_transaction() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // A helper function to construct a public transfer to the given address.
+    let transfer = |rng: &mut TestRng| {
+        let inputs = [Value::from_str(&format!("{address}")).unwrap(), Value::from_str("10u64").unwrap()];
+        ledger.vm.execute(&private_key, ("credits.aleo", "transfer_public"), inputs.iter(), None, 0, None, rng).unwrap()
+    };
+
+    // Add a block with a transfer to the ledger.
+    let block_1 =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transfer(rng)], rng).unwrap();
+    ledger.advance_to_next_block(&block_1).unwrap();
+
+    // Construct the next block with a new transfer.
+    let block_2 =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transfer(rng)], rng).unwrap();
+
+    // Construct a block that also replays the transfer from the first block, as the second transaction.
+    let replayed_transaction = block_1.transactions().iter().next().unwrap().clone();
+    let replayed_transaction_id = replayed_transaction.id();
+    let transactions =
+        Transactions::from_iter([block_2.transactions().iter().next().unwrap().clone(), replayed_transaction]);
+    let block = Block::new_beacon(
+        &private_key,
+        block_2.previous_hash(),
+        *block_2.header(),
+        block_2.ratifications().clone(),
+        None,
+        transactions,
+        vec![],
+        rng,
+    )
+    .unwrap();
+
+    // Ensure the error deterministically identifies the replayed transaction.
+    for _ in 0..4 {
+        let error = ledger.check_next_block(&block, rng).unwrap_err().to_string();
+        assert!(error.starts_with(&format!("Invalid transaction '{replayed_transaction_id}'")), "{error}");
+    }
+}
+
+#[test]
+fn test_check_median_time_past() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the ledger.
+    let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let ledger = crate::test_helpers::sample_ledger(private_key, rng);
+
+    // Add two blocks to the ledger.
+    for _ in 0..2 {
+        let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+        ledger.check_next_block(&block, rng).unwrap();
+        ledger.advance_to_next_block(&block).unwrap();
+    }
+
+    // Construct the next block.
+    let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+    // Ensure the median time past is not checked below the activation height.
+    assert!(block.height() < CurrentNetwork::MEDIAN_TIME_PAST_HEIGHT);
+
+    // Constructs the same block, with the given timestamp.
+    let mut with_timestamp = |timestamp: i64| {
+        let metadata = block.header().metadata();
+        let metadata = Metadata::new(
+            metadata.network(),
+            metadata.round(),
+            metadata.height(),
+            metadata.cumulative_weight(),
+            metadata.cumulative_proof_target(),
+            metadata.coinbase_target(),
+            metadata.proof_target(),
+            metadata.last_coinbase_target(),
+            metadata.last_coinbase_timestamp(),
+            timestamp,
+        )
+        .unwrap();
+        let header = Header::from(
+            block.previous_state_root(),
+            block.transactions_root(),
+            block.finalize_root(),
+            block.ratifications_root(),
+            block.solutions_root(),
+            block.header().subdag_root(),
+            metadata,
+        )
+        .unwrap();
+        Block::new_beacon(
+            &private_key,
+            block.previous_hash(),
+            header,
+            block.ratifications().clone(),
+            None,
+            block.transactions().clone(),
+            vec![],
+            rng,
+        )
+        .unwrap()
+    };
+
+    // Ensure a block with a timestamp before, or equal to, the median time past is rejected.
+    let median_time_past = ledger.get_median_time_past(ledger.latest_height()).unwrap();
+    for timestamp in [median_time_past - 1, median_time_past] {
+        let error = ledger.check_median_time_past(&with_timestamp(timestamp)).unwrap_err().to_string();
+        assert!(error.contains("at or before the median time past"), "{error}");
+    }
+    // Ensure a block with a timestamp just after the median time past is accepted.
+    ledger.check_median_time_past(&with_timestamp(median_time_past + 1)).unwrap();
+}
+
 #[test]
 fn test_split_candidate_solutions() {
     let rng = &mut TestRng::default();
@@ -160,4 +160,38 @@ mod test {
         println!("\nShould not verify (i.e. verifier messages should print below):");
         assert!(!verifying_key.verify("test", &[one, one + one], &proof));
     }
+
+    #[test]
+    fn test_verifying_key_fingerprint() -> Result<()> {
+        // Load two distinct verifying keys.
+        let verifying_key = |function_name: &str| -> Result<VerifyingKey<CurrentNetwork>> {
+            Ok(VerifyingKey::new(CurrentNetwork::get_credits_verifying_key(function_name.to_string())?.clone()))
+        };
+        let transfer_public = verifying_key("transfer_public")?;
+        let transfer_private = verifying_key("transfer_private")?;
+
+        // Ensure equal verifying keys share a fingerprint.
+        let candidate = VerifyingKey::<CurrentNetwork>::from_bytes_le(&transfer_public.to_bytes_le()?)?;
+        assert_eq!(transfer_public.fingerprint()?, candidate.fingerprint()?);
+        // Ensure different verifying keys have different fingerprints.
+        assert_ne!(transfer_public.fingerprint()?, transfer_private.fingerprint()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_proving_key_from_reader() -> Result<()> {
+        let assignment = crate::test_helpers::sample_assignment();
+        let (proving_key, verifying_key) = crate::test_helpers::sample_keys();
+
+        // Load the proving key from a reader.
+        let reader = std::io::Cursor::new(proving_key.to_bytes_le()?);
+        let candidate = ProvingKey::<CurrentNetwork>::read_le(reader)?;
+        assert_eq!(proving_key.to_bytes_le()?, candidate.to_bytes_le()?);
+
+        // Ensure the loaded proving key produces proofs for the original verifying key.
+        let proof = candidate.prove("test", &assignment, &mut TestRng::default())?;
+        let one = <Circuit as Environment>::BaseField::one();
+        assert!(verifying_key.verify("test", &[one, one], &proof));
+        Ok(())
+    }
 }
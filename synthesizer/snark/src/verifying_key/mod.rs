@@ -18,6 +18,7 @@ mod bytes;
 mod parse;
 mod serialize;
 
+use snarkvm_algorithms::crypto_hash::sha256;
 use std::collections::BTreeMap;
 
 #[derive(Clone, PartialEq, Eq)]
@@ -87,6 +88,12 @@ impl<N: Network> VerifyingKey<N> {
             }
         }
     }
+
+    /// Returns the fingerprint of the verifying key, defined as the SHA-256 hash of its byte encoding.
+    /// This allows two parties to check they hold the same verifying key without exchanging it.
+    pub fn fingerprint(&self) -> Result<[u8; 32]> {
+        Ok(sha256(&self.to_bytes_le()?))
+    }
 }
 
 impl<N: Network> Deref for VerifyingKey<N> {